@@ -0,0 +1,1012 @@
+//! Build script: compiles the roadmap files, validates them and precomputes
+//! the default layouts.
+//!
+//! Sections, topics and dependencies are authored in `roadmap/sXX_*.toml`
+//! (one file per section) plus `roadmap/cross_links.toml`; see
+//! `roadmap/README.md` for the format. They are parsed here and written to
+//! `$OUT_DIR/roadmap.rs` as the `SECTIONS`, `TOPICS` and `DEPENDENCIES`
+//! statics that `src/data/mod.rs` includes.
+//!
+//! `roadmap/tracks.toml` lists the roadmaps the app offers, each a subset of
+//! those sections with the spine re-chained through them. They become the
+//! `ROADMAPS` static in the same file.
+//!
+//! `roadmap/presets.toml` lists role presets, each a set of sections. Every
+//! roadmap is cut down to each preset as well, giving `PRESETS` and
+//! `PRESET_ROADMAPS`.
+//!
+//! Each topic's description and resources are authored in
+//! `content/<topic_id>.md`: YAML front matter for the resources, then the
+//! description as plain-text paragraphs (Markdown syntax is not rendered).
+//! The drawer title is the topic's own. They become the `CONTENT` table in
+//! `$OUT_DIR/content.rs`.
+//!
+//! `validate_roadmap` then runs over the result together with the topic
+//! content. Problems are reported as cargo warnings pointing at the file and
+//! line they come from, and any error fails the build.
+//!
+//! Finally the `compute_layout` result of each roadmap for the default
+//! `LayoutConfig` (both orientations, nothing collapsed) goes to
+//! `$OUT_DIR/layouts.rs`, embedded via `src/precomputed.rs`.
+
+// Shared with the crate, which uses more of them than the build script.
+#[allow(dead_code)]
+#[path = "src/layout/mod.rs"]
+mod layout;
+#[allow(dead_code)]
+#[path = "src/models/mod.rs"]
+mod models;
+#[allow(dead_code)]
+#[path = "src/data/validate.rs"]
+mod validate;
+
+use layout::tree::{LayoutConfig, LayoutOrientation, LayoutResult, compute_layout};
+use models::owned::OwnedResource;
+use models::roadmap::{
+  Dependency, DependencyKind, Hours, Level, Placement, Section, SectionLayout, Topic, TopicContent,
+  TopicType,
+};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Write};
+use std::path::{Path, PathBuf};
+use toml::Spanned;
+use validate::{Diagnostic, Severity, validate_roadmap};
+
+/// Directory holding the roadmap files, relative to the crate root.
+const ROADMAP_DIR: &str = "roadmap";
+/// The file of links between non-adjacent sections.
+const CROSS_LINKS_FILE: &str = "cross_links.toml";
+/// The file listing the roadmaps built from the sections.
+const TRACKS_FILE: &str = "tracks.toml";
+/// The file listing the role presets.
+const PRESETS_FILE: &str = "presets.toml";
+/// Directory holding one Markdown file per topic, relative to the crate root.
+const CONTENT_DIR: &str = "content";
+/// Front matter delimiter line in the content files.
+const FRONT_MATTER_FENCE: &str = "---";
+
+// ---- File format ----
+
+/// One `roadmap/sXX_*.toml` file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SectionFile {
+  section: SectionDef,
+  #[serde(default)]
+  topics: Vec<TopicDef>,
+  #[serde(default)]
+  dependencies: Vec<LinkDef>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SectionDef {
+  id: Spanned<String>,
+  title: String,
+  order: Spanned<u8>,
+  #[serde(default)]
+  layout: LayoutKind,
+  cols: Option<Spanned<usize>>,
+  /// The topic the previous and next sections connect to.
+  spine: Spanned<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+enum LayoutKind {
+  #[default]
+  List,
+  Grid,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TopicDef {
+  id: Spanned<String>,
+  title: String,
+  level: Level,
+  #[serde(rename = "type", default)]
+  topic_type: TopicType,
+  #[serde(default)]
+  placement: Placement,
+  row: Option<usize>,
+  /// Estimated study time: `6` or `[4, 8]`.
+  hours: Option<Hours>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LinkDef {
+  from: Spanned<String>,
+  to: Spanned<String>,
+  kind: Option<Spanned<DependencyKind>>,
+  label: Option<String>,
+}
+
+/// `roadmap/cross_links.toml`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CrossLinksFile {
+  #[serde(default)]
+  links: Vec<LinkDef>,
+}
+
+/// `roadmap/tracks.toml`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TracksFile {
+  #[serde(default)]
+  tracks: Vec<TrackDef>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TrackDef {
+  id: Spanned<String>,
+  title: String,
+  /// Section IDs; every section if left out.
+  sections: Option<Vec<Spanned<String>>>,
+}
+
+/// `roadmap/presets.toml`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PresetsFile {
+  #[serde(default)]
+  presets: Vec<PresetDef>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PresetDef {
+  id: Spanned<String>,
+  title: String,
+  sections: Vec<Spanned<String>>,
+}
+
+/// Front matter of one `content/<topic_id>.md` file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FrontMatter {
+  #[serde(default)]
+  resources: Vec<OwnedResource>,
+}
+
+// ---- Loading ----
+
+/// A position in a roadmap file, for error messages.
+#[derive(Clone)]
+struct Location {
+  file: &'static str,
+  line: usize,
+}
+
+impl fmt::Display for Location {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}:{}", self.file, self.line)
+  }
+}
+
+/// One roadmap file's text, for turning byte spans into line numbers.
+struct Source {
+  file: &'static str,
+  text: String,
+}
+
+impl Source {
+  fn at(&self, span: std::ops::Range<usize>) -> Location {
+    let line = self.text[..span.start.min(self.text.len())]
+      .matches('\n')
+      .count()
+      + 1;
+    Location {
+      file: self.file,
+      line,
+    }
+  }
+}
+
+/// One roadmap of `tracks.toml`, cut out of the full one.
+struct Track {
+  id: &'static str,
+  title: &'static str,
+  sections: Vec<Section>,
+  topics: Vec<Topic>,
+  dependencies: Vec<Dependency>,
+}
+
+/// One preset of `presets.toml`.
+struct Preset {
+  id: &'static str,
+  title: &'static str,
+  /// In roadmap order.
+  sections: Vec<&'static str>,
+}
+
+/// The compiled roadmap, plus where each item was defined.
+#[derive(Default)]
+struct Roadmap {
+  sections: Vec<Section>,
+  topics: Vec<Topic>,
+  /// Section-internal links, then `spine_links`, then cross-links.
+  dependencies: Vec<Dependency>,
+  /// Where the spine connections sit in `dependencies`.
+  spine_links: std::ops::Range<usize>,
+  /// The spine topic of each section that has a valid one.
+  spines: HashMap<&'static str, &'static str>,
+  tracks: Vec<Track>,
+  presets: Vec<Preset>,
+  /// Each track cut down to each preset it shares sections with, tagged
+  /// with the preset ID.
+  preset_tracks: Vec<(&'static str, Track)>,
+  /// Every definition of each section or topic ID, in file order.
+  ids: HashMap<&'static str, Vec<Location>>,
+  /// `(from, to)` endpoint locations of each dependency, by index.
+  links: Vec<(Location, Location)>,
+  /// Topic content, in file name order.
+  content: Vec<TopicContent>,
+  /// The file each `content` entry comes from.
+  content_files: HashMap<&'static str, Location>,
+  /// Problems that stop the roadmap compiling at all.
+  errors: Vec<String>,
+}
+
+/// The body as paragraphs separated by a blank line, each joined onto one
+/// line so hard wrapping in the file doesn't reach the page. Everything else
+/// is kept as typed: the drawer shows plain text, not rendered Markdown.
+fn paragraphs<'a>(lines: impl Iterator<Item = &'a str>) -> String {
+  let mut paragraphs: Vec<String> = Vec::new();
+  let mut current: Vec<&str> = Vec::new();
+  for line in lines.chain(std::iter::once("")) {
+    if line.trim().is_empty() {
+      if !current.is_empty() {
+        paragraphs.push(current.join(" "));
+        current.clear();
+      }
+    } else {
+      current.extend(line.split_whitespace());
+    }
+  }
+  paragraphs.join("\n\n")
+}
+
+/// Moves `s` to static storage, matching the `&'static str` fields of the models.
+fn leak(s: String) -> &'static str {
+  Box::leak(s.into_boxed_str())
+}
+
+fn read_source(path: &Path, root: &Path) -> Source {
+  let file = leak(
+    path
+      .strip_prefix(root)
+      .unwrap_or(path)
+      .display()
+      .to_string(),
+  );
+  let text = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("reading {file}: {e}"));
+  Source { file, text }
+}
+
+fn parse<T: for<'de> Deserialize<'de>>(source: &Source, errors: &mut Vec<String>) -> Option<T> {
+  match toml::from_str(&source.text) {
+    Ok(parsed) => Some(parsed),
+    Err(e) => {
+      let at = match e.span() {
+        Some(span) => source.at(span).to_string(),
+        None => source.file.to_string(),
+      };
+      errors.push(format!("{at}: {}", e.message()));
+      None
+    }
+  }
+}
+
+impl Roadmap {
+  fn load(root: &Path) -> Self {
+    let dir = root.join(ROADMAP_DIR);
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(&dir)
+      .unwrap_or_else(|e| panic!("reading {}: {e}", dir.display()))
+      .map(|entry| entry.unwrap().path())
+      .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+      .filter(|p| {
+        ![CROSS_LINKS_FILE, TRACKS_FILE, PRESETS_FILE]
+          .iter()
+          .any(|name| p.ends_with(name))
+      })
+      .collect();
+    paths.sort();
+
+    let mut roadmap = Roadmap::default();
+    let mut files = Vec::new();
+    for path in &paths {
+      let source = read_source(path, root);
+      if let Some(file) = parse::<SectionFile>(&source, &mut roadmap.errors) {
+        files.push((source, file));
+      }
+    }
+    files.sort_by_key(|(_, file)| *file.section.order.get_ref());
+
+    // Sections, with their own topics and dependencies.
+    let mut orders: HashMap<u8, Location> = HashMap::new();
+    let mut spines = Vec::new();
+    for (source, file) in files {
+      let order = *file.section.order.get_ref();
+      let at = source.at(file.section.order.span());
+      if let Some(first) = orders.get(&order) {
+        roadmap.errors.push(format!(
+          "{at}: section order {order} is already used at {first}"
+        ));
+      }
+      orders.insert(order, at);
+
+      if let Some(spine) = roadmap.add_section(&source, file) {
+        let section = roadmap.sections.last().unwrap().id;
+        roadmap.spines.insert(section, spine.0);
+        spines.push(spine);
+      }
+    }
+
+    // Spine connections, from each section to the next.
+    let start = roadmap.dependencies.len();
+    for pair in spines.windows(2) {
+      let (from, from_at) = &pair[0];
+      let (to, to_at) = &pair[1];
+      roadmap.dependencies.push(Dependency {
+        from,
+        to,
+        kind: DependencyKind::Spine,
+        label: None,
+      });
+      roadmap.links.push((from_at.clone(), to_at.clone()));
+    }
+    roadmap.spine_links = start..roadmap.dependencies.len();
+
+    // Cross-links between non-adjacent sections.
+    let source = read_source(&dir.join(CROSS_LINKS_FILE), root);
+    if let Some(file) = parse::<CrossLinksFile>(&source, &mut roadmap.errors) {
+      for link in file.links {
+        roadmap.add_link(&source, link);
+      }
+    }
+
+    roadmap.load_content(root);
+    roadmap.load_tracks(&dir.join(TRACKS_FILE), root);
+    roadmap.load_presets(&dir.join(PRESETS_FILE), root);
+    roadmap
+  }
+
+  /// Reads `tracks.toml` and cuts each roadmap out of the loaded sections.
+  fn load_tracks(&mut self, path: &Path, root: &Path) {
+    let source = read_source(path, root);
+    let Some(file) = parse::<TracksFile>(&source, &mut self.errors) else {
+      return;
+    };
+    if file.tracks.is_empty() {
+      self.errors.push(format!(
+        "{}: define at least one [[tracks]] entry",
+        source.file
+      ));
+    }
+
+    let mut ids: HashMap<String, Location> = HashMap::new();
+    for def in file.tracks {
+      let id = self.check_id(&source, "roadmap", def.id, &mut ids);
+      let included: HashSet<String> = match def.sections {
+        None => self.sections.iter().map(|s| s.id.to_string()).collect(),
+        Some(listed) => self.listed_sections(&source, &format!("roadmap `{id}`"), listed),
+      };
+      let track = self.cut_track(id, leak(def.title), &included);
+      self.tracks.push(track);
+    }
+  }
+
+  /// Reads `presets.toml` and cuts each track down to each preset.
+  fn load_presets(&mut self, path: &Path, root: &Path) {
+    let source = read_source(path, root);
+    let Some(file) = parse::<PresetsFile>(&source, &mut self.errors) else {
+      return;
+    };
+
+    let mut ids: HashMap<String, Location> = HashMap::new();
+    for def in file.presets {
+      let id = self.check_id(&source, "preset", def.id, &mut ids);
+      let listed = self.listed_sections(&source, &format!("preset `{id}`"), def.sections);
+      let sections = self
+        .sections
+        .iter()
+        .map(|s| s.id)
+        .filter(|s| listed.contains(*s))
+        .collect();
+      self.presets.push(Preset {
+        id,
+        title: leak(def.title),
+        sections,
+      });
+    }
+
+    for preset in &self.presets {
+      for track in &self.tracks {
+        let included: HashSet<String> = track
+          .sections
+          .iter()
+          .filter(|s| preset.sections.contains(&s.id))
+          .map(|s| s.id.to_string())
+          .collect();
+        if !included.is_empty() {
+          let cut = self.cut_track(track.id, track.title, &included);
+          self.preset_tracks.push((preset.id, cut));
+        }
+      }
+    }
+  }
+
+  /// Checks a track or preset ID (`kind`) is non-empty and not in `seen`.
+  fn check_id(
+    &mut self,
+    source: &Source,
+    kind: &str,
+    id: Spanned<String>,
+    seen: &mut HashMap<String, Location>,
+  ) -> &'static str {
+    let at = source.at(id.span());
+    let id = id.into_inner();
+    if let Some(first) = seen.get(&id) {
+      self
+        .errors
+        .push(format!("{at}: {kind} `{id}` is already defined at {first}"));
+    }
+    if id.is_empty() {
+      self
+        .errors
+        .push(format!("{at}: {kind} ID must not be empty"));
+    }
+    seen.insert(id.clone(), at);
+    leak(id)
+  }
+
+  /// The section IDs in `listed`, reporting unknown and repeated ones as
+  /// mistakes of `owner`.
+  fn listed_sections(
+    &mut self,
+    source: &Source,
+    owner: &str,
+    listed: Vec<Spanned<String>>,
+  ) -> HashSet<String> {
+    let mut included = HashSet::new();
+    for section in listed {
+      let at = source.at(section.span());
+      let section = section.into_inner();
+      if !self.sections.iter().any(|s| s.id == section) {
+        self
+          .errors
+          .push(format!("{at}: {owner} names unknown section `{section}`"));
+      } else if !included.insert(section.clone()) {
+        self
+          .errors
+          .push(format!("{at}: {owner} lists section `{section}` twice"));
+      }
+    }
+    included
+  }
+
+  /// The sections in `included`, their topics, and the links among them:
+  /// internal links, a spine chain through just these sections, then
+  /// cross-links, matching the order of the full `dependencies`.
+  fn cut_track(&self, id: &'static str, title: &'static str, included: &HashSet<String>) -> Track {
+    let sections: Vec<Section> = self
+      .sections
+      .iter()
+      .filter(|s| included.contains(s.id))
+      .cloned()
+      .collect();
+    let topics: Vec<Topic> = self
+      .topics
+      .iter()
+      .filter(|t| included.contains(t.section_id))
+      .cloned()
+      .collect();
+    let topic_ids: HashSet<&str> = topics.iter().map(|t| t.id).collect();
+    let within = |d: &&Dependency| topic_ids.contains(d.from) && topic_ids.contains(d.to);
+
+    let spines: Vec<&'static str> = sections
+      .iter()
+      .filter_map(|s| self.spines.get(s.id).copied())
+      .collect();
+    let dependencies = self.dependencies[..self.spine_links.start]
+      .iter()
+      .filter(within)
+      .cloned()
+      .chain(spines.windows(2).map(|pair| Dependency {
+        from: pair[0],
+        to: pair[1],
+        kind: DependencyKind::Spine,
+        label: None,
+      }))
+      .chain(
+        self.dependencies[self.spine_links.end..]
+          .iter()
+          .filter(within)
+          .cloned(),
+      )
+      .collect();
+
+    Track {
+      id,
+      title,
+      sections,
+      topics,
+      dependencies,
+    }
+  }
+
+  /// Reads `content/*.md`; the file stem is the topic ID.
+  fn load_content(&mut self, root: &Path) {
+    let dir = root.join(CONTENT_DIR);
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(&dir)
+      .unwrap_or_else(|e| panic!("reading {}: {e}", dir.display()))
+      .map(|entry| entry.unwrap().path())
+      .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+      .collect();
+    paths.sort();
+
+    for path in &paths {
+      let source = read_source(path, root);
+      let id = leak(path.file_stem().unwrap().to_string_lossy().into_owned());
+      if let Some(content) = self.parse_content(&source, id) {
+        self.content_files.insert(id, source.at(0..0));
+        self.content.push(content);
+      }
+    }
+  }
+
+  fn parse_content(&mut self, source: &Source, id: &'static str) -> Option<TopicContent> {
+    // `---`, the YAML front matter, `---`, then the description.
+    let mut lines = source.text.split_inclusive('\n');
+    if lines.next().map(str::trim_end) != Some(FRONT_MATTER_FENCE) {
+      self.errors.push(format!(
+        "{}: content must start with a `{FRONT_MATTER_FENCE}` front matter line",
+        source.at(0..0)
+      ));
+      return None;
+    }
+    let mut yaml = String::new();
+    let mut closed = false;
+    for line in lines.by_ref() {
+      if line.trim_end() == FRONT_MATTER_FENCE {
+        closed = true;
+        break;
+      }
+      yaml.push_str(line);
+    }
+    if !closed {
+      self.errors.push(format!(
+        "{}: front matter has no closing `{FRONT_MATTER_FENCE}` line",
+        source.at(0..0)
+      ));
+      return None;
+    }
+
+    let front: FrontMatter = match serde_norway::from_str(&yaml) {
+      Ok(front) => front,
+      Err(e) => {
+        // Report the position in the file; the front matter starts on its
+        // second line.
+        let message = e.to_string();
+        let error = match e.location() {
+          Some(l) => {
+            let suffix = format!(" at line {} column {}", l.line(), l.column());
+            let message = message.strip_suffix(&suffix).unwrap_or(&message);
+            format!("{}:{}:{}: {message}", source.file, l.line() + 1, l.column())
+          }
+          None => format!("{}: {message}", source.file),
+        };
+        self.errors.push(error);
+        return None;
+      }
+    };
+
+    // Titled like the topic; content without one is reported as orphaned.
+    let title = self
+      .topics
+      .iter()
+      .find(|t| t.id == id)
+      .map_or(id, |t| t.title);
+    Some(TopicContent {
+      id,
+      title,
+      description: leak(paragraphs(lines)),
+      resources: front
+        .resources
+        .into_iter()
+        .map(OwnedResource::into_static)
+        .collect::<Vec<_>>()
+        .leak(),
+    })
+  }
+
+  /// Adds a section's definition, topics and dependencies. Returns its
+  /// spine topic and where that was named.
+  fn add_section(
+    &mut self,
+    source: &Source,
+    file: SectionFile,
+  ) -> Option<(&'static str, Location)> {
+    let def = file.section;
+    let id_at = source.at(def.id.span());
+    let id = leak(def.id.into_inner());
+    self.ids.entry(id).or_default().push(id_at.clone());
+
+    let layout = match (def.layout, def.cols) {
+      (LayoutKind::List, None) => SectionLayout::List,
+      (LayoutKind::Grid, Some(cols)) if *cols.get_ref() > 0 => SectionLayout::Grid {
+        cols: cols.into_inner(),
+      },
+      (LayoutKind::Grid, Some(cols)) => {
+        let at = source.at(cols.span());
+        self.errors.push(format!("{at}: `cols` must be at least 1"));
+        SectionLayout::List
+      }
+      (LayoutKind::Grid, None) => {
+        self.errors.push(format!(
+          "{id_at}: section `{id}` uses layout = \"grid\" but sets no `cols`"
+        ));
+        SectionLayout::List
+      }
+      (LayoutKind::List, Some(cols)) => {
+        let at = source.at(cols.span());
+        self
+          .errors
+          .push(format!("{at}: `cols` only applies to layout = \"grid\""));
+        SectionLayout::List
+      }
+    };
+    self.sections.push(Section {
+      id,
+      title: leak(def.title),
+      order: def.order.into_inner(),
+      layout,
+    });
+
+    let mut own_topics = HashSet::new();
+    for t in file.topics {
+      let at = source.at(t.id.span());
+      let topic_id = leak(t.id.into_inner());
+      own_topics.insert(topic_id);
+      self.ids.entry(topic_id).or_default().push(at);
+      self.topics.push(Topic {
+        id: topic_id,
+        title: leak(t.title),
+        section_id: id,
+        level: t.level,
+        topic_type: t.topic_type,
+        placement: t.placement,
+        row: t.row,
+        estimated_hours: t.hours,
+      });
+    }
+
+    for link in file.dependencies {
+      self.add_link(source, link);
+    }
+
+    let spine_at = source.at(def.spine.span());
+    let spine = def.spine.into_inner();
+    if !own_topics.contains(spine.as_str()) {
+      self.errors.push(format!(
+        "{spine_at}: spine `{spine}` is not a topic of section `{id}`"
+      ));
+      return None;
+    }
+    Some((leak(spine), spine_at))
+  }
+
+  fn add_link(&mut self, source: &Source, link: LinkDef) {
+    let from_at = source.at(link.from.span());
+    let to_at = source.at(link.to.span());
+    if let Some(kind) = &link.kind
+      && *kind.get_ref() == DependencyKind::Spine
+    {
+      let at = source.at(kind.span());
+      self.errors.push(format!(
+        "{at}: spine links are added automatically; use another `kind`"
+      ));
+    }
+    self.dependencies.push(Dependency {
+      from: leak(link.from.into_inner()),
+      to: leak(link.to.into_inner()),
+      kind: link.kind.map(Spanned::into_inner).unwrap_or_default(),
+      label: link.label.map(leak),
+    });
+    self.links.push((from_at, to_at));
+  }
+
+  /// Where `diagnostic` comes from, if it points at one place.
+  fn locate(&self, diagnostic: &Diagnostic) -> Option<&Location> {
+    match diagnostic {
+      // The later definition is the duplicate.
+      Diagnostic::DuplicateSectionId { id }
+      | Diagnostic::DuplicateTopicId { id }
+      | Diagnostic::TopicIdIsSectionId { id } => self.ids.get(id.as_str())?.last(),
+      Diagnostic::ReservedCharInId { id, .. }
+      | Diagnostic::UnknownSection { topic: id, .. }
+      | Diagnostic::RowOutOfRange { topic: id, .. }
+      | Diagnostic::MissingContent { topic: id } => self.ids.get(id.as_str())?.first(),
+      Diagnostic::UnknownDependencyEndpoint { from, to, missing } => {
+        let i = self
+          .dependencies
+          .iter()
+          .position(|d| d.from == from && d.to == to)?;
+        let (from_at, to_at) = &self.links[i];
+        Some(if missing == from { from_at } else { to_at })
+      }
+      Diagnostic::OrphanContent { id } => self.content_files.get(id.as_str()),
+      Diagnostic::DependencyCycle { topics } => self.ids.get(topics.first()?.as_str())?.first(),
+    }
+  }
+}
+
+// ---- Code generation ----
+
+fn write_roadmap(out: &mut String, roadmap: &Roadmap) {
+  out.push_str("pub static SECTIONS: &[Section] = &[\n");
+  write_sections(out, "  ", &roadmap.sections);
+  out.push_str("];\n\n");
+
+  out.push_str("pub static TOPICS: &[Topic] = &[\n");
+  write_topics(out, "  ", &roadmap.topics);
+  out.push_str("];\n\n");
+
+  out.push_str("pub static DEPENDENCIES: &[Dependency] = &[\n");
+  write_dependencies(out, "  ", &roadmap.dependencies);
+  out.push_str("];\n\n");
+
+  out.push_str("pub static ROADMAPS: &[Roadmap] = &[\n");
+  for track in &roadmap.tracks {
+    out.push_str("  ");
+    write_track(out, "  ", track);
+    out.push_str(",\n");
+  }
+  out.push_str("];\n\n");
+
+  out.push_str("pub static PRESETS: &[Preset] = &[\n");
+  for preset in &roadmap.presets {
+    writeln!(
+      out,
+      "  Preset {{ id: {:?}, title: {:?}, sections: &{:?} }},",
+      preset.id, preset.title, preset.sections,
+    )
+    .unwrap();
+  }
+  out.push_str("];\n\n");
+
+  out.push_str("pub static PRESET_ROADMAPS: &[PresetRoadmap] = &[\n");
+  for (preset_id, track) in &roadmap.preset_tracks {
+    writeln!(out, "  PresetRoadmap {{\n    preset_id: {preset_id:?},").unwrap();
+    out.push_str("    roadmap: ");
+    write_track(out, "    ", track);
+    out.push_str(",\n  },\n");
+  }
+  out.push_str("];\n");
+}
+
+/// Writes `track` as a `Roadmap` expression, its lines indented by `indent`.
+fn write_track(out: &mut String, indent: &str, track: &Track) {
+  writeln!(
+    out,
+    "Roadmap {{\n{indent}  id: {:?},\n{indent}  title: {:?},",
+    track.id, track.title
+  )
+  .unwrap();
+  let inner = format!("{indent}    ");
+  writeln!(out, "{indent}  sections: &[").unwrap();
+  write_sections(out, &inner, &track.sections);
+  writeln!(out, "{indent}  ],\n{indent}  topics: &[").unwrap();
+  write_topics(out, &inner, &track.topics);
+  writeln!(out, "{indent}  ],\n{indent}  dependencies: &[").unwrap();
+  write_dependencies(out, &inner, &track.dependencies);
+  write!(out, "{indent}  ],\n{indent}  content: CONTENT,\n{indent}}}").unwrap();
+}
+
+fn write_sections(out: &mut String, indent: &str, sections: &[Section]) {
+  for s in sections {
+    writeln!(
+      out,
+      "{indent}Section {{ id: {:?}, title: {:?}, order: {}, layout: SectionLayout::{:?} }},",
+      s.id, s.title, s.order, s.layout,
+    )
+    .unwrap();
+  }
+}
+
+fn write_topics(out: &mut String, indent: &str, topics: &[Topic]) {
+  for t in topics {
+    let row = match t.row {
+      Some(r) => format!("Some({r})"),
+      None => "None".to_string(),
+    };
+    writeln!(
+      out,
+      "{indent}Topic {{ id: {:?}, title: {:?}, section_id: {:?}, level: Level::{:?}, \
+       topic_type: TopicType::{:?}, placement: Placement::{:?}, row: {row}, \
+       estimated_hours: {:?} }},",
+      t.id, t.title, t.section_id, t.level, t.topic_type, t.placement, t.estimated_hours,
+    )
+    .unwrap();
+  }
+}
+
+fn write_dependencies(out: &mut String, indent: &str, dependencies: &[Dependency]) {
+  for d in dependencies {
+    writeln!(
+      out,
+      "{indent}Dependency {{ from: {:?}, to: {:?}, kind: DependencyKind::{:?}, label: {:?} }},",
+      d.from, d.to, d.kind, d.label,
+    )
+    .unwrap();
+  }
+}
+
+fn write_content(out: &mut String, content: &[TopicContent]) {
+  out.push_str("pub static CONTENT: &[TopicContent] = &[\n");
+  for c in content {
+    writeln!(
+      out,
+      "  TopicContent {{ id: {:?}, title: {:?}, description: {:?}, resources: &[",
+      c.id, c.title, c.description,
+    )
+    .unwrap();
+    for r in c.resources {
+      writeln!(
+        out,
+        "    Resource {{ label: {:?}, url: {:?}, badge: BadgeKind::{:?} }},",
+        r.label, r.url, r.badge,
+      )
+      .unwrap();
+    }
+    out.push_str("  ] },\n");
+  }
+  out.push_str("];\n");
+}
+
+/// Writes `layout` as a `StaticLayout` expression.
+fn write_layout(out: &mut String, layout: &LayoutResult) {
+  out.push_str("StaticLayout {\n");
+
+  out.push_str("  groups: &[\n");
+  for g in &layout.groups {
+    writeln!(
+      out,
+      "    GroupBox {{ section_id: {:?}, x: {:?}, y: {:?}, width: {:?}, height: {:?}, \
+       label: {:?}, collapsed: {:?} }},",
+      g.section_id, g.x, g.y, g.width, g.height, g.label, g.collapsed,
+    )
+    .unwrap();
+  }
+  out.push_str("  ],\n");
+
+  out.push_str("  topics: &[\n");
+  for p in &layout.topics {
+    writeln!(
+      out,
+      "    TopicPosition {{ topic_id: {:?}, section_id: {:?}, x: {:?}, y: {:?}, \
+       width: {:?}, height: {:?} }},",
+      p.topic_id, p.section_id, p.x, p.y, p.width, p.height,
+    )
+    .unwrap();
+  }
+  out.push_str("  ],\n");
+
+  out.push_str("  edges: &[\n");
+  for e in &layout.edges {
+    let points: Vec<String> = e
+      .points
+      .iter()
+      .map(|(x, y)| format!("({x:?}, {y:?})"))
+      .collect();
+    writeln!(
+      out,
+      "    StaticEdge {{ from: {:?}, to: {:?}, points: &[{}], is_cross_section: {:?}, \
+       kind: DependencyKind::{:?}, label: {:?} }},",
+      e.from,
+      e.to,
+      points.join(", "),
+      e.is_cross_section,
+      e.kind,
+      e.label,
+    )
+    .unwrap();
+  }
+  out.push_str("  ],\n");
+
+  writeln!(
+    out,
+    "  min_x: {:?},\n  total_width: {:?},\n  total_height: {:?},\n}}",
+    layout.min_x, layout.total_width, layout.total_height,
+  )
+  .unwrap();
+}
+
+fn main() {
+  for path in [
+    "build.rs",
+    ROADMAP_DIR,
+    CONTENT_DIR,
+    "src/data",
+    "src/layout",
+    "src/models",
+  ] {
+    println!("cargo:rerun-if-changed={path}");
+  }
+
+  let root = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+  let roadmap = Roadmap::load(&root);
+  for e in &roadmap.errors {
+    println!("cargo:warning=roadmap: {e}");
+  }
+  if !roadmap.errors.is_empty() {
+    panic!(
+      "{} roadmap file error(s); see the warnings above",
+      roadmap.errors.len()
+    );
+  }
+
+  let diagnostics = validate_roadmap(
+    &roadmap.sections,
+    &roadmap.topics,
+    &roadmap.dependencies,
+    &roadmap.content,
+  );
+  for d in &diagnostics {
+    match roadmap.locate(d) {
+      Some(at) => println!("cargo:warning=roadmap: {at}: {d}"),
+      None => println!("cargo:warning=roadmap: {d}"),
+    }
+  }
+  let errors = diagnostics
+    .iter()
+    .filter(|d| d.severity() == Severity::Error)
+    .count();
+  if errors > 0 {
+    panic!("roadmap data has {errors} error(s); see the warnings above");
+  }
+
+  let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+
+  let mut out = String::from("// @generated by build.rs from roadmap/*.toml; do not edit.\n\n");
+  write_roadmap(&mut out, &roadmap);
+  std::fs::write(out_dir.join("roadmap.rs"), out).unwrap();
+
+  let mut out = String::from("// @generated by build.rs from content/*.md; do not edit.\n\n");
+  write_content(&mut out, &roadmap.content);
+  std::fs::write(out_dir.join("content.rs"), out).unwrap();
+
+  let collapsed = HashSet::new();
+  let mut out = String::from("// @generated by build.rs from roadmap/*.toml; do not edit.\n\n");
+  out.push_str("pub static LAYOUTS: &[TrackLayouts] = &[\n");
+  for track in &roadmap.tracks {
+    writeln!(out, "  TrackLayouts {{\n    roadmap_id: {:?},", track.id).unwrap();
+    for (field, orientation) in [
+      ("horizontal", LayoutOrientation::Horizontal),
+      ("vertical", LayoutOrientation::Vertical),
+    ] {
+      let config = LayoutConfig {
+        orientation,
+        ..LayoutConfig::default()
+      };
+      let layout = compute_layout(
+        &track.sections,
+        &track.topics,
+        &track.dependencies,
+        &collapsed,
+        &config,
+      );
+      write!(out, "    {field}: ").unwrap();
+      write_layout(&mut out, &layout);
+      out.push_str(",\n");
+    }
+    out.push_str("  },\n");
+  }
+  out.push_str("];\n");
+  std::fs::write(out_dir.join("layouts.rs"), out).unwrap();
+}
@@ -1,14 +1,15 @@
-use crate::models::roadmap::{BadgeKind, NodeStatus, TopicContent};
+use crate::models::roadmap::{BadgeKind, Hours, NodeStatus, TopicContent};
 use crate::state::roadmap_state::RoadmapState;
 use leptos::*;
 
 #[component]
 pub fn TopicDetail(
-  content: TopicContent,
+  content: &'static TopicContent,
   on_close: Callback<()>,
   is_open: bool,
   topic_id: &'static str,
   section_label: &'static str,
+  estimated_hours: Option<Hours>,
 ) -> impl IntoView {
   let state = use_context::<RoadmapState>().expect("RoadmapState context not found");
 
@@ -49,6 +50,9 @@ pub fn TopicDetail(
                   <h1 class="drawer__title" id="drawer-title">
                       {content.title}
                   </h1>
+                  {estimated_hours.map(|hours| view! {
+                      <div class="drawer__estimate">{format!("About {hours} of study")}</div>
+                  })}
               </div>
               <button
                   class="drawer__close"
@@ -69,9 +73,11 @@ pub fn TopicDetail(
 
               // ── Description ───────────────────────────────────────────
               <div class="drawer__section">
-                  <p class="drawer__description">
-                      {content.description}
-                  </p>
+                  {content
+                      .description
+                      .split("\n\n")
+                      .map(|paragraph| view! { <p class="drawer__description">{paragraph}</p> })
+                      .collect_view()}
               </div>
 
               // ── Learning Status ────────────────────────────────────────
@@ -2,19 +2,46 @@
 //!
 //! Renders the full SVG canvas including:
 //! - Section group boxes (`SectionGroup`)
-//! - Topic nodes (`RoadmapNode`) with status and search-dimming
-//! - Connector edges (`RoadmapEdge`) with horizontal cross-section routing
+//! - Topic nodes (`RoadmapNode`) with status and search-dimming, each reactive
+//!   on its own
+//! - Connector edges (`RoadmapEdge`) following the routes computed by the layout engine,
+//!   styled by dependency kind and explained by an `EdgeLegend`
+//!
+//! The canvas pans and zooms (wheel, drag, pinch, zoom buttons, `Minimap`)
+//! by rewriting the `viewBox` held in `RoadmapState::view_box`. Zoomed out
+//! past `DetailLevel::Overview`, each section is drawn as a summary tile and
+//! the topic nodes and intra-section edges are left out.
+//!
+//! Only nodes and edges near the visible region are mounted; a
+//! `SpatialIndex` over the layout answers the viewport queries.
 
 use crate::components::roadmap::edge::{ArrowheadMarker, EdgeData, RoadmapEdge};
 use crate::components::roadmap::group::{GroupBoxData, SectionGroup};
+use crate::components::roadmap::legend::EdgeLegend;
+use crate::components::roadmap::minimap::{Minimap, MinimapData};
 use crate::components::roadmap::node::{NodeData, RoadmapNode};
-use crate::layout::tree::{LayoutConfig, LayoutResult, TopicPosition};
-use crate::models::roadmap::{Dependency, Topic};
+use crate::layout::spatial::SpatialIndex;
+use crate::layout::text;
+use crate::layout::tree::{LayoutConfig, LayoutOrientation, LayoutResult, TopicPosition};
+use crate::models::roadmap::{DependencyKind, Topic, total_hours};
 use crate::state::roadmap_state::RoadmapState;
+use crate::state::view_box::{DetailLevel, ViewBox};
+use leptos::wasm_bindgen::JsCast;
 use leptos::*;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
+/// Zoom change per pixel of wheel scroll.
+const WHEEL_ZOOM_RATE: f64 = 0.0015;
+/// Zoom change per click of the zoom buttons.
+const ZOOM_STEP: f64 = 1.25;
+/// Pointer travel before a press turns into a drag (keeps node clicks working).
+const DRAG_THRESHOLD_PX: f64 = 4.0;
+/// Extra area mounted around the visible region, as a fraction of its size
+/// on each side, so short pans don't remount nodes at the edge.
+const CULL_MARGIN: f64 = 0.25;
+
 // ---------------------------------------------------------------------------
 // Public data type passed from the page
 // ---------------------------------------------------------------------------
@@ -22,7 +49,6 @@ use std::rc::Rc;
 #[derive(Clone)]
 pub struct DiagramData {
   pub topics: &'static [Topic],
-  pub dependencies: &'static [Dependency],
   pub layout: LayoutResult,
   pub config: LayoutConfig,
 }
@@ -31,65 +57,37 @@ pub struct DiagramData {
 // Internal helpers
 // ---------------------------------------------------------------------------
 
-fn find_topic<'a>(topics: &'a [Topic], id: &str) -> Option<&'a Topic> {
-  topics.iter().find(|t| t.id == id)
-}
-
-fn find_position<'a>(positions: &'a [TopicPosition], id: &str) -> Option<&'a TopicPosition> {
-  positions.iter().find(|p| p.topic_id == id)
-}
-
 /// Returns `true` when `term_lc` (already lowercased) appears in the topic title.
 fn topic_matches(topic: &Topic, term_lc: &str) -> bool {
   !term_lc.is_empty() && topic.title.to_lowercase().contains(term_lc)
 }
 
-/// Try to scroll the `.roadmap-container` div so the matched node is visible.
-fn scroll_to_match(topic_id: &str) {
-  let window = match web_sys::window() {
-    Some(w) => w,
-    None => return,
-  };
-  let document = match window.document() {
-    Some(d) => d,
-    None => return,
-  };
+/// Pan the view so the matched node sits in the middle of a `client`-sized
+/// canvas.
+fn scroll_to_match(view_box: RwSignal<ViewBox>, pos: &TopicPosition, client: (f64, f64)) {
+  view_box.update(|vb| {
+    if vb.is_set() {
+      *vb = vb.centered_on(pos.x + pos.width / 2.0, pos.y + pos.height / 2.0, client);
+    }
+  });
+}
 
-  let selector = format!("[data-topic-id=\"{}\"]", topic_id);
-  let element = match document.query_selector(&selector) {
-    Ok(Some(el)) => el,
-    _ => return,
-  };
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+  (a.0 - b.0).hypot(a.1 - b.1)
+}
 
-  // Prefer scrolling inside the horizontal container
-  let container = document.query_selector(".roadmap-container").ok().flatten();
-
-  if let Some(c) = container {
-    let c_rect = c.get_bounding_client_rect();
-    let el_rect = element.get_bounding_client_rect();
-
-    // How far the element's left edge is from the container's left edge in the viewport
-    let offset_in_viewport = el_rect.left() - c_rect.left();
-    // Current horizontal scroll of the container
-    let current_sl = c.scroll_left() as f64;
-    // Target: centre the element horizontally with some left margin
-    let target_x = (current_sl + offset_in_viewport - 120.0).max(0.0);
-
-    // ScrollToOptions works on Window; use scrollLeft directly via JS eval is tricky.
-    // Fallback: scroll the window to at least bring it into the y-axis view.
-    let opts = web_sys::ScrollToOptions::new();
-    opts.set_behavior(web_sys::ScrollBehavior::Smooth);
-    opts.set_top(window.scroll_y().unwrap_or(0.0) + el_rect.top() - 150.0);
-    opts.set_left(target_x);
-    window.scroll_to_with_scroll_to_options(&opts);
-  } else {
-    // Plain vertical scroll fallback
-    let rect = element.get_bounding_client_rect();
-    let opts = web_sys::ScrollToOptions::new();
-    opts.set_behavior(web_sys::ScrollBehavior::Smooth);
-    opts.set_top(window.scroll_y().unwrap_or(0.0) + rect.top() - 150.0);
-    window.scroll_to_with_scroll_to_options(&opts);
-  }
+fn midpoint(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+  ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+/// Pointers currently down on the canvas, in client coordinates.
+#[derive(Default)]
+struct Gesture {
+  pointers: Vec<(i32, (f64, f64))>,
+  /// Where the first pointer went down.
+  start: (f64, f64),
+  /// Past the drag threshold; the canvas has captured the pointers.
+  dragging: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -102,7 +100,6 @@ pub fn RoadmapDiagram(props: DiagramData) -> impl IntoView {
   let state = use_context::<RoadmapState>().expect("RoadmapState context not found");
 
   let search_term = state.search_term;
-  let progress = state.progress;
   let selected_id = state.selected_topic_id;
 
   // Callback that wires node clicks to the drawer.
@@ -110,6 +107,11 @@ pub fn RoadmapDiagram(props: DiagramData) -> impl IntoView {
     selected_id.set(Some(id));
   });
 
+  // Callback that collapses / expands a section from its header bar.
+  let on_group_toggle = Callback::new(move |id: &'static str| {
+    state.toggle_section(id);
+  });
+
   // Pre-compute lowercased search term once per change.
   let search_lc = create_memo(move |_| search_term.get().to_lowercase());
 
@@ -118,7 +120,55 @@ pub fn RoadmapDiagram(props: DiagramData) -> impl IntoView {
   let topics = props.topics;
   let layout_topics = props.layout.topics.clone();
   let layout_groups = props.layout.groups.clone();
-  let topics_for_scroll = props.topics;
+  let topic_by_id: HashMap<&'static str, &'static Topic> =
+    topics.iter().map(|t| (t.id, t)).collect();
+  let positions_for_scroll: HashMap<&'static str, TopicPosition> = props
+    .layout
+    .topics
+    .iter()
+    .map(|p| (p.topic_id, p.clone()))
+    .collect();
+  let view_box = state.view_box;
+
+  // ── Canvas geometry ────────────────────────────────────────────────────
+  let svg_ref = create_node_ref::<svg::Svg>();
+  let content = (
+    props.layout.min_x,
+    0.0,
+    props.layout.total_width,
+    props.layout.total_height,
+  );
+
+  // Canvas bounds in client pixels, once laid out.
+  let canvas_rect = move || {
+    let rect = svg_ref.get_untracked()?.get_bounding_client_rect();
+    (rect.width() > 0.0 && rect.height() > 0.0).then_some(rect)
+  };
+  let client_size = move || canvas_rect().map(|r| (r.width(), r.height()));
+
+  // The canvas is measured on demand; memos that depend on its size re-run
+  // when the window is resized.
+  let resized = create_trigger();
+  create_effect(move |_| {
+    if let Some(window) = web_sys::window() {
+      let closure = leptos::wasm_bindgen::closure::Closure::wrap(
+        Box::new(move || resized.notify()) as Box<dyn Fn()>,
+      );
+
+      let _ = window.add_event_listener_with_callback("resize", closure.as_ref().unchecked_ref());
+
+      on_cleanup(move || {
+        if let Some(window) = web_sys::window() {
+          let _ =
+            window.remove_event_listener_with_callback("resize", closure.as_ref().unchecked_ref());
+        }
+      });
+    }
+  });
+  let canvas_size = Signal::derive(move || {
+    resized.track();
+    client_size()
+  });
 
   // ── Scroll effect ──────────────────────────────────────────────────────
   let last_scrolled: Rc<RefCell<Option<&'static str>>> = Rc::new(RefCell::new(None));
@@ -131,67 +181,89 @@ pub fn RoadmapDiagram(props: DiagramData) -> impl IntoView {
         *last_scrolled.borrow_mut() = None;
         return;
       }
-      // Find first topic whose title matches.
-      let first = topics_for_scroll
+      // Find first visible topic whose title matches.
+      let first = topics
         .iter()
-        .find(|t| topic_matches(t, &term))
-        .map(|t| t.id);
+        .filter(|t| topic_matches(t, &term))
+        .find_map(|t| positions_for_scroll.get(t.id));
 
-      if let Some(id) = first {
+      if let (Some(pos), Some(client)) = (first, client_size()) {
         let mut last = last_scrolled.borrow_mut();
-        if last.map(|prev| prev != id).unwrap_or(true) {
-          scroll_to_match(id);
-          *last = Some(id);
+        if last.map(|prev| prev != pos.topic_id).unwrap_or(true) {
+          scroll_to_match(view_box, pos, client);
+          *last = Some(pos.topic_id);
         }
       }
     });
   }
 
+  // Semantic zoom: changes only when the zoom crosses the overview threshold.
+  let detail = create_memo(move |_| {
+    let vb = view_box.get();
+    canvas_size
+      .get()
+      .map(|client| vb.detail_level(client))
+      .unwrap_or_default()
+  });
+  let overview = Signal::derive(move || detail.get() == DetailLevel::Overview);
+
+  // Viewport culling: indices of the nodes and edges to mount. The memo only
+  // notifies when the set changes, not on every pan frame.
+  let spatial = SpatialIndex::new(&props.layout);
+  let visible = create_memo(move |_| {
+    let vb = view_box.get();
+    let region = match canvas_size.get() {
+      Some(client) if vb.is_set() => {
+        let (x, y, w, h) = vb.visible(client);
+        (
+          x - w * CULL_MARGIN,
+          y - h * CULL_MARGIN,
+          w * (1.0 + 2.0 * CULL_MARGIN),
+          h * (1.0 + 2.0 * CULL_MARGIN),
+        )
+      }
+      _ => content,
+    };
+    spatial.query(region)
+  });
+
   // ── Build static edge list ─────────────────────────────────────────────
-  // Edges are not reactive (positions never change); only class/style varies
-  // with state, which is handled on the node side, not the edge side.
-  let edge_props: Vec<EdgeData> = props
-    .dependencies
+  // Edge geometry never changes; which edges are mounted follows `visible`
+  // and the detail level.
+  let edge_props: Rc<Vec<EdgeData>> = props
+    .layout
+    .edges
     .iter()
-    .filter_map(|dep| {
-      let from_pos = find_position(&props.layout.topics, dep.from)?;
-      let to_pos = find_position(&props.layout.topics, dep.to)?;
-
-      let is_cross = from_pos.section_id != to_pos.section_id;
-
-      let (x1, y1, x2, y2) = if is_cross {
-        // Cross-section: exit via right edge of "from", enter via left edge of "to".
-        // Groups flow left → right so from_pos is always to the left of to_pos.
-        let x1 = from_pos.x + from_pos.width;
-        let y1 = from_pos.y + config.node_height / 2.0;
-        let x2 = to_pos.x;
-        let y2 = to_pos.y + config.node_height / 2.0;
-        (x1, y1, x2, y2)
-      } else {
-        // Intra-section: bottom-centre of "from" → top-centre of "to".
-        let x1 = from_pos.x + from_pos.width / 2.0;
-        let y1 = from_pos.y + config.node_height;
-        let x2 = to_pos.x + to_pos.width / 2.0;
-        let y2 = to_pos.y;
-        (x1, y1, x2, y2)
-      };
-
-      Some(EdgeData {
-        from_id: dep.from,
-        to_id: dep.to,
-        x1,
-        y1,
-        x2,
-        y2,
-        is_cross_section: is_cross,
-      })
+    .map(|route| EdgeData {
+      from_id: route.from,
+      to_id: route.to,
+      points: route.points.clone(),
+      is_cross_section: route.is_cross_section,
+      kind: route.kind,
+      label: route.label,
     })
+    .collect::<Vec<_>>()
+    .into();
+
+  // Only the kinds this roadmap uses go in the legend.
+  let legend_kinds: Vec<DependencyKind> = DependencyKind::ALL
+    .into_iter()
+    .filter(|kind| edge_props.iter().any(|e| e.kind == *kind))
     .collect();
 
   // ── Static group box views ─────────────────────────────────────────────
   let group_views: Vec<_> = layout_groups
     .iter()
     .map(|g| {
+      let section_topics: Vec<&'static str> = topics
+        .iter()
+        .filter(|t| t.section_id == g.section_id)
+        .map(|t| t.id)
+        .collect();
+      let total = section_topics.len();
+      let hours = total_hours(topics.iter().filter(|t| t.section_id == g.section_id));
+      let completed = Signal::derive(move || state.completed_among(&section_topics));
+
       let gdata = GroupBoxData {
         section_id: g.section_id,
         x: g.x,
@@ -200,81 +272,299 @@ pub fn RoadmapDiagram(props: DiagramData) -> impl IntoView {
         height: g.height,
         label: g.label,
         header_height: config.header_height,
+        collapsed: g.collapsed,
+        completed,
+        total,
+        hours,
+        overview,
+        on_toggle: on_group_toggle,
       };
       view! { <SectionGroup props=gdata /> }
     })
     .collect();
 
-  // ── viewBox ────────────────────────────────────────────────────────────
-  let viewbox = format!(
-    "{} 0 {} {}",
-    props.layout.min_x, props.layout.total_width, props.layout.total_height
-  );
+  // ── Pan / zoom ─────────────────────────────────────────────────────────
+  let reset_view = move || {
+    if let Some(client) = client_size() {
+      view_box.set(ViewBox::actual_size(content.0, content.1, client));
+    }
+  };
+  let fit_view = move || {
+    if let Some(client) = client_size() {
+      view_box.set(ViewBox::fit(content, client));
+    }
+  };
+  let zoom_by = move |factor: f64| {
+    if let Some(client) = client_size() {
+      view_box.update(|vb| {
+        let centre = vb.to_diagram((client.0 / 2.0, client.1 / 2.0), client);
+        *vb = vb.zoomed_at(factor, centre, client);
+      });
+    }
+  };
+
+  // First mount: show the diagram 1:1 from its top-left corner. Later
+  // re-renders (collapsing a section, orientation flips) keep the view.
+  svg_ref.on_load(move |_| {
+    request_animation_frame(move || {
+      if !view_box.get_untracked().is_set() {
+        reset_view();
+      }
+    });
+  });
+
+  let on_wheel = move |ev: ev::WheelEvent| {
+    ev.prevent_default();
+    let Some(rect) = canvas_rect() else {
+      return;
+    };
+    let client = (rect.width(), rect.height());
+    let p = (
+      ev.client_x() as f64 - rect.left(),
+      ev.client_y() as f64 - rect.top(),
+    );
+    let factor = (-ev.delta_y() * WHEEL_ZOOM_RATE).exp();
+    view_box.update(|vb| {
+      let anchor = vb.to_diagram(p, client);
+      *vb = vb.zoomed_at(factor, anchor, client);
+    });
+  };
+
+  // One pointer drags the view; two pinch-zoom around their midpoint.
+  let gesture = Rc::new(RefCell::new(Gesture::default()));
+  let capture = move |id: i32| {
+    if let Some(el) = svg_ref.get_untracked() {
+      let _ = el.set_pointer_capture(id);
+    }
+  };
+
+  let on_pointer_down = {
+    let gesture = Rc::clone(&gesture);
+    move |ev: ev::PointerEvent| {
+      let mut g = gesture.borrow_mut();
+      let id = ev.pointer_id();
+      let p = (ev.client_x() as f64, ev.client_y() as f64);
+      // A pointer released off the canvas before it was captured never sent
+      // us its `pointerup`; a new press replaces its stale entry.
+      g.pointers.retain(|(pid, _)| *pid != id);
+      if g.pointers.is_empty() {
+        g.start = p;
+        g.dragging = false;
+      }
+      g.pointers.push((id, p));
+    }
+  };
+
+  let on_pointer_move = {
+    let gesture = Rc::clone(&gesture);
+    move |ev: ev::PointerEvent| {
+      let mut g = gesture.borrow_mut();
+      let id = ev.pointer_id();
+      let Some(i) = g.pointers.iter().position(|(pid, _)| *pid == id) else {
+        return;
+      };
+      let Some(rect) = canvas_rect() else {
+        return;
+      };
+      let client = (rect.width(), rect.height());
+      let p = (ev.client_x() as f64, ev.client_y() as f64);
+      let prev = std::mem::replace(&mut g.pointers[i].1, p);
+
+      match g.pointers.len() {
+        1 => {
+          if !g.dragging {
+            if distance(p, g.start) < DRAG_THRESHOLD_PX {
+              return;
+            }
+            g.dragging = true;
+            capture(id);
+          }
+          view_box.update(|vb| *vb = vb.panned(p.0 - prev.0, p.1 - prev.1, client));
+        }
+        2 => {
+          if !g.dragging {
+            g.dragging = true;
+            capture(id);
+          }
+          let other = g.pointers[1 - i].1;
+          let (old_mid, new_mid) = (midpoint(prev, other), midpoint(p, other));
+          let old_span = distance(prev, other);
+          let factor = if old_span > 0.0 {
+            distance(p, other) / old_span
+          } else {
+            1.0
+          };
+          view_box.update(|vb| {
+            let anchor = vb.to_diagram((old_mid.0 - rect.left(), old_mid.1 - rect.top()), client);
+            *vb = vb.zoomed_at(factor, anchor, client).panned(
+              new_mid.0 - old_mid.0,
+              new_mid.1 - old_mid.1,
+              client,
+            );
+          });
+        }
+        _ => {}
+      }
+    }
+  };
+
+  let end_pointer = {
+    let gesture = Rc::clone(&gesture);
+    move |ev: ev::PointerEvent| {
+      let id = ev.pointer_id();
+      gesture.borrow_mut().pointers.retain(|(pid, _)| *pid != id);
+      if let Some(el) = svg_ref.get_untracked()
+        && el.has_pointer_capture(id)
+      {
+        let _ = el.release_pointer_capture(id);
+      }
+    }
+  };
+
+  let minimap = MinimapData {
+    groups: layout_groups.clone(),
+    topics,
+    content,
+    client: canvas_size,
+  };
+
+  let view_box_attr = move || {
+    let vb = view_box.get();
+    if vb.is_set() {
+      vb.to_attr()
+    } else {
+      format!("{} {} {} {}", content.0, content.1, content.2, content.3)
+    }
+  };
+
+  // Indices for the keyed node / edge layers. Zoomed out, only the
+  // cross-section edges remain.
+  let visible_edges = {
+    let edge_props = Rc::clone(&edge_props);
+    move || {
+      let overview = overview.get();
+      visible.with(|v| {
+        v.edges
+          .iter()
+          .copied()
+          .filter(|&i| !overview || edge_props[i].is_cross_section)
+          .collect::<Vec<_>>()
+      })
+    }
+  };
+  let visible_topics = move || {
+    if overview.get() {
+      Vec::new()
+    } else {
+      visible.with(|v| v.topics.clone())
+    }
+  };
 
   // ── View ───────────────────────────────────────────────────────────────
-  let svg_width = format!("{:.0}", props.layout.total_width);
-  let svg_height = format!("{:.0}", props.layout.total_height);
+  let svg_class = match config.orientation {
+    LayoutOrientation::Horizontal => "roadmap-diagram roadmap-diagram--horizontal",
+    LayoutOrientation::Vertical => "roadmap-diagram roadmap-diagram--vertical",
+  };
 
   view! {
-      <svg
-          class="roadmap-diagram roadmap-diagram--horizontal"
-          viewBox=viewbox
-          width=svg_width
-          height=svg_height
-          xmlns="http://www.w3.org/2000/svg"
-      >
-          <ArrowheadMarker />
-
-          // Groups layer — behind edges and nodes
-          <g class="groups-layer">
-              {group_views}
-          </g>
-
-          // Edges layer — static, rendered once
-          <g class="edges-layer">
-              {edge_props
-                  .into_iter()
-                  .map(|ep| view! { <RoadmapEdge props=ep /> })
-                  .collect_view()}
-          </g>
-
-          // Nodes layer — reactive on search + progress
-          <g class="nodes-layer">
-              {move || {
-                  let term = search_lc.get();
-                  let prog = progress.get();
-                  let has_search = !term.is_empty();
-
-                  layout_topics
-                      .iter()
-                      .filter_map(|tp| {
-                          let topic = find_topic(topics, tp.topic_id)?;
-                          let is_highlighted = topic_matches(topic, &term);
-                          let is_dimmed = has_search && !is_highlighted;
-                          let status = prog
-                              .get(topic.id)
-                              .copied()
-                              .unwrap_or_default();
-
-                          Some(NodeData {
-                              id: topic.id,
-                              title: topic.title,
-                              level: topic.level,
-                              topic_type: topic.topic_type,
-                              x: tp.x,
-                              y: tp.y,
-                              width: tp.width,
-                              height: config.node_height,
-                              on_click: on_topic_click,
-                              is_highlighted,
-                              is_dimmed,
-                              status,
+      <div class="roadmap-canvas">
+          // Zoom controls — overlaid on the top-right corner of the canvas
+          <div class="diagram-controls">
+              <button
+                  class="diagram-controls__button"
+                  title="Zoom in"
+                  on:click=move |_| zoom_by(ZOOM_STEP)
+              >
+                  "+"
+              </button>
+              <button
+                  class="diagram-controls__button"
+                  title="Zoom out"
+                  on:click=move |_| zoom_by(1.0 / ZOOM_STEP)
+              >
+                  "−"
+              </button>
+              <button
+                  class="diagram-controls__button"
+                  title="Fit to screen"
+                  on:click=move |_| fit_view()
+              >
+                  "Fit"
+              </button>
+              <button
+                  class="diagram-controls__button"
+                  title="Reset zoom"
+                  on:click=move |_| reset_view()
+              >
+                  "Reset"
+              </button>
+          </div>
+
+          // Overview — bottom-right corner of the canvas
+          <Minimap props=minimap />
+
+          // Edge styles — bottom-left corner of the canvas
+          <EdgeLegend kinds=legend_kinds />
+
+          <svg
+              node_ref=svg_ref
+              class=svg_class
+              viewBox=view_box_attr
+              preserveAspectRatio="xMinYMin meet"
+              xmlns="http://www.w3.org/2000/svg"
+              on:wheel=on_wheel
+              on:pointerdown=on_pointer_down
+              on:pointermove=on_pointer_move
+              on:pointerup=end_pointer.clone()
+              on:pointercancel=end_pointer.clone()
+              on:lostpointercapture=end_pointer
+          >
+              <ArrowheadMarker />
+
+              // Groups layer — behind edges and nodes
+              <g class="groups-layer">
+                  {group_views}
+              </g>
+
+              // Edges layer — culled to the viewport; keyed, so only edges
+              // entering or leaving the view are mounted / unmounted
+              <g class="edges-layer">
+                  <For
+                      each=visible_edges
+                      key=|&i| i
+                      children=move |i| view! { <RoadmapEdge props=edge_props[i].clone() /> }
+                  />
+              </g>
+
+              // Nodes layer — keyed and culled to the viewport. Each node
+              // tracks its own status and search state.
+              <g class="nodes-layer">
+                  <For
+                      each=visible_topics
+                      key=|&i| i
+                      children=move |i| {
+                          let tp = &layout_topics[i];
+                          topic_by_id.get(tp.topic_id).map(|topic| {
+                              let nd = NodeData {
+                                  id: topic.id,
+                                  title: topic.title,
+                                  lines: text::title_lines(topic.title, &config),
+                                  line_height: config.line_height,
+                                  level: topic.level,
+                                  topic_type: topic.topic_type,
+                                  x: tp.x,
+                                  y: tp.y,
+                                  width: tp.width,
+                                  height: tp.height,
+                                  on_click: on_topic_click,
+                                  search_lc,
+                              };
+                              view! { <RoadmapNode props=nd /> }
                           })
-                      })
-                      .map(|nd| view! { <RoadmapNode props=nd /> })
-                      .collect_view()
-              }}
-          </g>
-      </svg>
+                      }
+                  />
+              </g>
+          </svg>
+      </div>
   }
 }
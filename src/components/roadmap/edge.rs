@@ -1,43 +1,70 @@
+use crate::models::roadmap::DependencyKind;
 use leptos::*;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct EdgeData {
   pub from_id: &'static str,
   pub to_id: &'static str,
-  pub x1: f64,
-  pub y1: f64,
-  pub x2: f64,
-  pub y2: f64,
+  /// Orthogonal polyline routed by the layout engine, source port first.
+  pub points: Vec<(f64, f64)>,
   pub is_cross_section: bool,
+  /// Picks the stroke: solid, dashed or dotted (see `EdgeLegend`).
+  pub kind: DependencyKind,
+  pub label: Option<&'static str>,
+}
+
+/// The point halfway along the polyline `points`.
+fn halfway(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+  let length = |a: (f64, f64), b: (f64, f64)| (b.0 - a.0).hypot(b.1 - a.1);
+  let total: f64 = points.windows(2).map(|w| length(w[0], w[1])).sum();
+
+  let mut left = total / 2.0;
+  for w in points.windows(2) {
+    let (a, b) = (w[0], w[1]);
+    let segment = length(a, b);
+    if segment > 0.0 && segment >= left {
+      let t = left / segment;
+      return Some((a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t));
+    }
+    left -= segment;
+  }
+  points.first().copied()
 }
 
 #[component]
 pub fn RoadmapEdge(props: EdgeData) -> impl IntoView {
-  let class_attr = if props.is_cross_section {
-    "roadmap-edge edge-cross-section"
-  } else {
-    "roadmap-edge"
-  };
-
-  // Edge routing logic based on connection type
-  let path_d = if (props.y1 - props.y2).abs() < 1.0 {
-    // Same row (horizontal branch connection) → Straight horizontal line
-    format!("M {} {} L {} {}", props.x1, props.y1, props.x2, props.y2)
-  } else if (props.x1 - props.x2).abs() < 1.0 {
-    // Same column (vertical spine) → Straight vertical line
-    format!("M {} {} L {} {}", props.x1, props.y1, props.x2, props.y2)
-  } else {
-    // Mixed: horizontal mid-step, then vertical, then horizontal to target
-    // This avoids the line "sliding" along the face of the target node.
-    let mid_x = (props.x1 + props.x2) / 2.0;
-    format!(
-      "M {} {} L {} {} L {} {} L {} {}",
-      props.x1, props.y1, mid_x, props.y1, mid_x, props.y2, props.x2, props.y2
-    )
-  };
+  let mut class_attr = format!("roadmap-edge roadmap-edge--{}", props.kind.as_str());
+  if props.is_cross_section {
+    class_attr.push_str(" edge-cross-section");
+  }
+  // Related topics have no order, so no arrowhead.
+  let marker_end = props.kind.is_ordered().then_some("url(#arrowhead)");
+
+  // The route is already obstacle-free; just join its bend points.
+  let path_d = props
+    .points
+    .iter()
+    .enumerate()
+    .map(|(i, (x, y))| format!("{} {} {}", if i == 0 { "M" } else { "L" }, x, y))
+    .collect::<Vec<_>>()
+    .join(" ");
+
+  let label = props
+    .label
+    .zip(halfway(&props.points))
+    .map(|(text, (x, y))| {
+      view! {
+          <text class="roadmap-edge__label" x=x y=y text-anchor="middle" dominant-baseline="middle">
+              {text}
+          </text>
+      }
+    });
 
   view! {
-      <path class=class_attr d=path_d fill="none" marker-end="url(#arrowhead)" />
+      <g>
+          <path class=class_attr d=path_d fill="none" marker-end=marker_end />
+          {label}
+      </g>
   }
 }
 
@@ -1,9 +1,30 @@
 //! Section group box SVG component.
 
+use crate::models::roadmap::Hours;
 use leptos::*;
+use std::f64::consts::PI;
+
+/// Inner spacing of the overview tile.
+const TILE_PADDING: f64 = 14.0;
+/// Largest radius of the overview completion ring.
+const TILE_RING_RADIUS: f64 = 36.0;
+/// Largest font size of the overview title, in diagram units.
+const TILE_TITLE_SIZE: f64 = 24.0;
+/// Approximate advance of one title character, as a fraction of the font size.
+const TILE_CHAR_ADVANCE: f64 = 0.62;
+/// Inset of the chevron and the summary from the header bar's ends.
+const HEADER_INSET: f64 = 12.0;
+/// Where the header title starts, past the chevron.
+const HEADER_TITLE_X: f64 = 28.0;
+/// Room kept between the header title and the summary.
+const HEADER_GAP: f64 = 10.0;
+/// Approximate advance of one header title character (uppercase, letter-spaced).
+const HEADER_TITLE_ADVANCE: f64 = 7.7;
+/// Approximate advance of one header summary character.
+const HEADER_SUMMARY_ADVANCE: f64 = 6.6;
 
 /// Data for rendering a single section group box.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct GroupBoxData {
   pub section_id: &'static str,
   pub x: f64,
@@ -12,30 +33,94 @@ pub struct GroupBoxData {
   pub height: f64,
   pub label: &'static str,
   pub header_height: f64,
+  /// Only the header bar is drawn; it shows `completed / total` instead of topics.
+  pub collapsed: bool,
+  /// Done + Skipped topics in the section.
+  pub completed: Signal<usize>,
+  pub total: usize,
+  /// Sum of the section's topic estimates, if any have one.
+  pub hours: Option<Hours>,
+  /// Zoomed out: draw the section as a summary tile instead of its topics.
+  pub overview: Signal<bool>,
+  /// Fired with `section_id` when the header bar is clicked.
+  pub on_toggle: Callback<&'static str>,
 }
 
 /// Renders an SVG group box with a coloured header bar and a title label.
 ///
 /// Structure:
 /// ```text
-/// ┌─────────────────────┐  ← header bar (orange tint)
-/// │  SECTION TITLE      │
-/// ├─────────────────────┤
-/// │  [Topic Node]       │
-/// │  [Topic Node]       │
-/// │  ...                │
-/// └─────────────────────┘
+/// ┌─────────────────────────────┐  ← header bar (orange tint)
+/// │ ▾ SECTION TITLE          6 h│
+/// ├─────────────────────────────┤
+/// │  [Topic Node]               │
+/// │  [Topic Node]               │
+/// │  ...                        │
+/// └─────────────────────────────┘
+/// ```
+///
+/// The estimate on the right is the sum of the topics' `estimated_hours`,
+/// left out if none has one. A title too long for the room left of it is
+/// cut short with an ellipsis. Clicking the header collapses the group to
+/// just its header bar:
+/// ```text
+/// ┌─────────────────────────────┐
+/// │ ▸ SECTION TI…      3/5 · 6 h│
+/// └─────────────────────────────┘
+/// ```
+///
+/// At overview zoom an expanded group is covered by a summary tile instead:
+/// ```text
+/// ┌─────────────────────────────┐
+/// │  ◔   SECTION TITLE          │
+/// │      3/5 · 5 topics · 6 h   │
+/// └─────────────────────────────┘
 /// ```
 #[component]
 pub fn SectionGroup(props: GroupBoxData) -> impl IntoView {
+  let tile = props.clone();
   let x = props.x;
   let y = props.y;
   let w = props.width;
   let h = props.height;
   let hh = props.header_height;
+  let section_id = props.section_id;
+  let on_toggle = props.on_toggle;
+  let collapsed = props.collapsed;
+  let completed = props.completed;
+  let total = props.total;
+  let hours = props.hours;
+  let overview = props.overview;
+  let label = props.label;
+
+  let class_attr = if collapsed {
+    "section-group section-group--collapsed"
+  } else {
+    "section-group"
+  };
+
+  // Right end of the header: the estimate, after the completion count when
+  // collapsed.
+  let summary = move || {
+    let hours = hours.map(|h| h.to_string());
+    if !collapsed {
+      return hours.unwrap_or_default();
+    }
+    let count = format!("{}/{}", completed.get(), total);
+    match hours {
+      Some(hours) => format!("{count} · {hours}"),
+      None => count,
+    }
+  };
+  // The title gets what the summary leaves of the header.
+  let title = move || {
+    let summary_width = summary().chars().count() as f64 * HEADER_SUMMARY_ADVANCE;
+    let room = w - HEADER_TITLE_X - HEADER_INSET - summary_width - HEADER_GAP;
+    truncate(label, (room / HEADER_TITLE_ADVANCE).max(0.0) as usize)
+  };
 
   view! {
-      <g class="section-group" data-section-id=props.section_id>
+      <g class=class_attr data-section-id=section_id>
           // Full background rect
           <rect
               x=x
@@ -46,36 +131,160 @@ pub fn SectionGroup(props: GroupBoxData) -> impl IntoView {
               ry="6"
               class="section-group__bg"
           />
-          // Header bar — rounded top corners only.
-          // Step 1: draw a rect with all corners rounded (matches the bg).
+          // Header bar — clickable, toggles the collapsed state.
+          <g class="section-group__toggle" on:click=move |_| on_toggle.call(section_id)>
+              // Header bar — rounded top corners only.
+              // Step 1: draw a rect with all corners rounded (matches the bg).
+              <rect
+                  x=x
+                  y=y
+                  width=w
+                  height=hh
+                  rx="6"
+                  ry="6"
+                  class="section-group__header"
+              />
+              // Step 2: square off the bottom half of the header's rounded corners
+              // by overlaying a plain rect over the lower portion. A collapsed
+              // group is all header, so it keeps every corner rounded.
+              {(!collapsed).then(|| view! {
+                  <rect
+                      x=x
+                      y=y + hh * 0.5
+                      width=w
+                      height=hh * 0.5
+                      class="section-group__header"
+                  />
+              })}
+              <text
+                  x=x + HEADER_INSET
+                  y=y + hh * 0.5
+                  class="section-group__chevron"
+                  dominant-baseline="central"
+              >
+                  {if collapsed { "▸" } else { "▾" }}
+              </text>
+              // Title label after the chevron, with the full title as a tooltip
+              <title>{label}</title>
+              <text
+                  x=x + HEADER_TITLE_X
+                  y=y + hh * 0.5
+                  class="section-group__title"
+                  dominant-baseline="central"
+              >
+                  {title}
+              </text>
+              // Completion summary replaces the hidden topics
+              {(collapsed || hours.is_some()).then(|| view! {
+                  <text
+                      x=x + w - HEADER_INSET
+                      y=y + hh * 0.5
+                      class="section-group__summary"
+                      text-anchor="end"
+                      dominant-baseline="central"
+                  >
+                      {summary}
+                  </text>
+              })}
+          </g>
+          {move || {
+              (overview.get() && !collapsed)
+                  .then(|| overview_tile(tile.clone()))
+          }}
+      </g>
+  }
+}
+
+/// Summary tile drawn over an expanded group at overview zoom: a completion
+/// ring on the left, the section title, topic count and estimate on the
+/// right.
+fn overview_tile(group: GroupBoxData) -> impl IntoView {
+  let (x, y, w, h) = (group.x, group.y, group.width, group.height);
+  let (label, completed, total) = (group.label, group.completed, group.total);
+  let r = ((h - 2.0 * TILE_PADDING) / 2.0).clamp(8.0, TILE_RING_RADIUS);
+  let cx = x + TILE_PADDING + r;
+  let cy = y + h * 0.5;
+  let circumference = 2.0 * PI * r;
+
+  // Shrink the title until it fits beside the ring.
+  let text_x = cx + r + TILE_PADDING;
+  let avail = (x + w - TILE_PADDING - text_x).max(0.0);
+  let chars = label.chars().count().max(1) as f64;
+  let title_size = (avail / (chars * TILE_CHAR_ADVANCE)).min(TILE_TITLE_SIZE);
+
+  let fraction = move || {
+    if total == 0 {
+      0.0
+    } else {
+      completed.get() as f64 / total as f64
+    }
+  };
+  let dash = move || {
+    let done = circumference * fraction();
+    format!("{:.2} {:.2}", done, circumference)
+  };
+  let topics_label = if total == 1 { "topic" } else { "topics" };
+  let hours_label = group.hours.map(|h| format!(" · {h}")).unwrap_or_default();
+
+  view! {
+      <g class="section-tile">
           <rect
               x=x
               y=y
               width=w
-              height=hh
+              height=h
               rx="6"
               ry="6"
-              class="section-group__header"
+              class="section-tile__bg"
           />
-          // Step 2: square off the bottom half of the header's rounded corners
-          // by overlaying a plain rect over the lower portion.
-          <rect
-              x=x
-              y=y + hh * 0.5
-              width=w
-              height=hh * 0.5
-              class="section-group__header"
+          <circle cx=cx cy=cy r=r class="section-tile__ring-track" />
+          <circle
+              cx=cx
+              cy=cy
+              r=r
+              class="section-tile__ring"
+              stroke-dasharray=dash
+              transform=format!("rotate(-90 {} {})", cx, cy)
           />
-          // Title label centred in the header bar
           <text
-              x=x + w * 0.5
-              y=y + hh * 0.5
-              class="section-group__title"
+              x=cx
+              y=cy
+              class="section-tile__pct"
               text-anchor="middle"
               dominant-baseline="central"
           >
-              {props.label}
+              {move || format!("{:.0}%", fraction() * 100.0)}
+          </text>
+          <text
+              x=text_x
+              y=cy - 4.0
+              class="section-tile__title"
+              font-size=title_size
+          >
+              {label}
+          </text>
+          <text
+              x=text_x
+              y=cy + 16.0
+              class="section-tile__count"
+              dominant-baseline="hanging"
+          >
+              {move || {
+                  format!("{}/{} · {} {}{}", completed.get(), total, total, topics_label, hours_label)
+              }}
           </text>
       </g>
   }
 }
+
+/// `label`, cut to at most `max_chars` characters with a trailing ellipsis.
+fn truncate(label: &str, max_chars: usize) -> String {
+  if label.chars().count() <= max_chars {
+    return label.to_string();
+  }
+  if max_chars == 0 {
+    return String::new();
+  }
+  let kept: String = label.chars().take(max_chars - 1).collect();
+  format!("{}…", kept.trim_end())
+}
@@ -0,0 +1,40 @@
+//! Key to the edge styles of the diagram.
+
+use crate::models::roadmap::DependencyKind;
+use leptos::*;
+
+/// Name and meaning of each kind, as shown in the legend.
+fn describe(kind: DependencyKind) -> (&'static str, &'static str) {
+  match kind {
+    DependencyKind::Prerequisite => ("Prerequisite", "Learn this first"),
+    DependencyKind::Recommended => ("Recommended", "Helps, but not required"),
+    DependencyKind::Related => ("Related", "Worth knowing together"),
+    DependencyKind::Spine => ("Next section", "The main path"),
+  }
+}
+
+/// Lists `kinds` with a sample of the edge each one is drawn as.
+#[component]
+pub fn EdgeLegend(kinds: Vec<DependencyKind>) -> impl IntoView {
+  let items = kinds
+    .into_iter()
+    .map(|kind| {
+      let (name, meaning) = describe(kind);
+      let class_attr = format!("roadmap-edge roadmap-edge--{}", kind.as_str());
+      view! {
+          <li class="edge-legend__item" title=meaning>
+              <svg class="edge-legend__sample" width="28" height="8" viewBox="0 0 28 8">
+                  <line class=class_attr x1="0" y1="4" x2="28" y2="4" />
+              </svg>
+              {name}
+          </li>
+      }
+    })
+    .collect_view();
+
+  view! {
+      <ul class="edge-legend" aria-label="Edge styles">
+          {items}
+      </ul>
+  }
+}
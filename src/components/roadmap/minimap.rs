@@ -0,0 +1,179 @@
+//! Minimap overview of the roadmap canvas.
+//!
+//! Draws every section group scaled down, tinted by how much of the section
+//! is complete, with a rectangle marking the part of the diagram currently
+//! in view. Clicking the minimap centres the main view on that point;
+//! dragging moves it continuously (grabbing the rectangle keeps its offset).
+
+use crate::layout::tree::GroupBox;
+use crate::models::roadmap::Topic;
+use crate::state::roadmap_state::RoadmapState;
+use crate::state::view_box::ViewBox;
+use leptos::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Largest size of the minimap in CSS pixels; the diagram is scaled to fit.
+const MAX_WIDTH_PX: f64 = 220.0;
+const MAX_HEIGHT_PX: f64 = 140.0;
+
+/// Data for rendering the minimap.
+#[derive(Clone, Debug)]
+pub struct MinimapData {
+  pub groups: Vec<GroupBox>,
+  pub topics: &'static [Topic],
+  /// Full diagram extent: `(x, y, width, height)`.
+  pub content: (f64, f64, f64, f64),
+  /// Size of the main canvas in client pixels, once laid out.
+  pub client: Signal<Option<(f64, f64)>>,
+}
+
+#[component]
+pub fn Minimap(props: MinimapData) -> impl IntoView {
+  let state = use_context::<RoadmapState>().expect("RoadmapState context not found");
+  let view_box = state.view_box;
+  let canvas = props.client;
+
+  // Part of the diagram the main canvas shows, which is wider or taller than
+  // the view box when their aspect ratios differ.
+  let shown = move || {
+    let vb = view_box.get();
+    let client = canvas.get()?;
+    vb.is_set().then(|| vb.visible(client))
+  };
+
+  let content = props.content;
+  let content_box = ViewBox {
+    x: content.0,
+    y: content.1,
+    width: content.2,
+    height: content.3,
+  };
+  let svg_ref = create_node_ref::<svg::Svg>();
+  let scale = (MAX_WIDTH_PX / content.2).min(MAX_HEIGHT_PX / content.3);
+  let width = format!("{:.0}", content.2 * scale);
+  let height = format!("{:.0}", content.3 * scale);
+
+  // Diagram point under a pointer event, using the minimap's own scale.
+  let to_diagram = move |ev: &ev::PointerEvent| {
+    let rect = svg_ref.get_untracked()?.get_bounding_client_rect();
+    let client = (rect.width(), rect.height());
+    if client.0 <= 0.0 || client.1 <= 0.0 {
+      return None;
+    }
+    let p = (
+      ev.client_x() as f64 - rect.left(),
+      ev.client_y() as f64 - rect.top(),
+    );
+    Some(content_box.to_diagram(p, client))
+  };
+
+  // Offset from the pointer to the view centre while dragging.
+  let grab: Rc<Cell<Option<(f64, f64)>>> = Rc::new(Cell::new(None));
+
+  let on_pointer_down = {
+    let grab = Rc::clone(&grab);
+    move |ev: ev::PointerEvent| {
+      let (Some(p), Some(client)) = (to_diagram(&ev), canvas.get_untracked()) else {
+        return;
+      };
+      let vb = view_box.get_untracked();
+      if !vb.is_set() {
+        return;
+      }
+      let (x, y, w, h) = vb.visible(client);
+      let centre = (x + w / 2.0, y + h / 2.0);
+      let inside = p.0 >= x && p.0 <= x + w && p.1 >= y && p.1 <= y + h;
+      let offset = if inside {
+        (centre.0 - p.0, centre.1 - p.1)
+      } else {
+        (0.0, 0.0)
+      };
+
+      grab.set(Some(offset));
+      view_box.set(vb.centered_on(p.0 + offset.0, p.1 + offset.1, client));
+      if let Some(el) = svg_ref.get_untracked() {
+        let _ = el.set_pointer_capture(ev.pointer_id());
+      }
+    }
+  };
+
+  let on_pointer_move = {
+    let grab = Rc::clone(&grab);
+    move |ev: ev::PointerEvent| {
+      let (Some(offset), Some(p), Some(client)) =
+        (grab.get(), to_diagram(&ev), canvas.get_untracked())
+      else {
+        return;
+      };
+      view_box.update(|vb| *vb = vb.centered_on(p.0 + offset.0, p.1 + offset.1, client));
+    }
+  };
+
+  let end_drag = move |_: ev::PointerEvent| grab.set(None);
+
+  let group_views = props
+    .groups
+    .iter()
+    .map(|g| {
+      let section_topics: Vec<&'static str> = props
+        .topics
+        .iter()
+        .filter(|t| t.section_id == g.section_id)
+        .map(|t| t.id)
+        .collect();
+      let total = section_topics.len().max(1);
+      let fill_opacity = move || {
+        let done = state.completed_among(&section_topics) as f64 / total as f64;
+        format!("{:.2}", 0.15 + 0.75 * done)
+      };
+
+      view! {
+          <rect
+              x=g.x
+              y=g.y
+              width=g.width
+              height=g.height
+              rx="6"
+              ry="6"
+              class="minimap__group"
+              fill-opacity=fill_opacity
+          />
+      }
+    })
+    .collect_view();
+
+  let viewport = move || {
+    shown().map(|(x, y, width, height)| {
+      view! {
+          <rect
+              x=x
+              y=y
+              width=width
+              height=height
+              class="minimap__viewport"
+              vector-effect="non-scaling-stroke"
+          />
+      }
+    })
+  };
+
+  view! {
+      <svg
+          node_ref=svg_ref
+          class="minimap"
+          width=width
+          height=height
+          viewBox=format!("{} {} {} {}", content.0, content.1, content.2, content.3)
+          preserveAspectRatio="xMinYMin meet"
+          xmlns="http://www.w3.org/2000/svg"
+          on:pointerdown=on_pointer_down
+          on:pointermove=on_pointer_move
+          on:pointerup=end_drag.clone()
+          on:pointercancel=end_drag
+      >
+          {group_views}
+          {viewport}
+      </svg>
+  }
+}
@@ -2,4 +2,6 @@ pub mod detail_view;
 pub mod diagram;
 pub mod edge;
 pub mod group;
+pub mod legend;
+pub mod minimap;
 pub mod node;
@@ -1,12 +1,17 @@
 //! SVG node component for rendering roadmap topics.
 
 use crate::models::roadmap::{Level, NodeStatus, TopicType};
+use crate::state::roadmap_state::RoadmapState;
 use leptos::*;
 
 #[derive(Clone, Debug)]
 pub struct NodeData {
   pub id: &'static str,
   pub title: &'static str,
+  /// `title` wrapped to fit the node width.
+  pub lines: Vec<String>,
+  /// Distance between wrapped title lines.
+  pub line_height: f64,
   pub level: Level,
   pub topic_type: TopicType,
   pub x: f64,
@@ -14,61 +19,80 @@ pub struct NodeData {
   pub width: f64,
   pub height: f64,
   pub on_click: Callback<&'static str>,
-  pub is_highlighted: bool,
-  pub is_dimmed: bool,
-  pub status: NodeStatus,
+  /// Lowercased search term, shared by every node.
+  pub search_lc: Memo<String>,
 }
 
+/// Renders one topic node.
+///
+/// The node is created once and owns memos for its own status, highlight and
+/// dim state, so a progress change or search keystroke only updates the
+/// attributes of the nodes it actually affects.
 #[component]
 pub fn RoadmapNode(props: NodeData) -> impl IntoView {
+  let state = use_context::<RoadmapState>().expect("RoadmapState context not found");
+  let progress = state.progress;
+  let id = props.id;
+  let search_lc = props.search_lc;
+
+  let status = create_memo(move |_| progress.with(|p| p.get(id).copied().unwrap_or_default()));
+  let title_lc = props.title.to_lowercase();
+  let is_highlighted =
+    create_memo(move |_| search_lc.with(|t| !t.is_empty() && title_lc.contains(t.as_str())));
+  let is_dimmed = create_memo(move |_| search_lc.with(|t| !t.is_empty()) && !is_highlighted.get());
+
   let type_class = match props.topic_type {
     TopicType::Main => "type-main",
     TopicType::Sub => "type-sub",
   };
 
-  let status_class = match props.status {
-    NodeStatus::Done => " node-done",
-    NodeStatus::InProgress => " node-in-progress",
-    NodeStatus::Skipped => " node-skipped",
-    NodeStatus::Untouched => "",
-  };
+  let class_attr = move || {
+    let status_class = match status.get() {
+      NodeStatus::Done => " node-done",
+      NodeStatus::InProgress => " node-in-progress",
+      NodeStatus::Skipped => " node-skipped",
+      NodeStatus::Untouched => "",
+    };
+    let highlight_class = if is_highlighted.get() {
+      " node-highlighted"
+    } else {
+      ""
+    };
+    let dimmed_class = if is_dimmed.get() { " node-dimmed" } else { "" };
 
-  let highlight_class = if props.is_highlighted {
-    " node-highlighted"
-  } else {
-    ""
+    format!(
+      "roadmap-node {}{}{}{}",
+      type_class, status_class, highlight_class, dimmed_class
+    )
   };
 
-  let dimmed_class = if props.is_dimmed { " node-dimmed" } else { "" };
-
-  let class_attr = format!(
-    "roadmap-node {}{}{}{}",
-    type_class, status_class, highlight_class, dimmed_class
-  );
-
-  let cursor_style = if props.is_dimmed {
-    "cursor: default; pointer-events: none;"
-  } else {
-    "cursor: pointer;"
+  let cursor_style = move || {
+    if is_dimmed.get() {
+      "cursor: default; pointer-events: none;"
+    } else {
+      "cursor: pointer;"
+    }
   };
 
-  let show_checkmark = props.status == NodeStatus::Done;
-  let show_accent = props.status == NodeStatus::InProgress;
+  let show_checkmark = move || status.get() == NodeStatus::Done;
+  let show_accent = move || status.get() == NodeStatus::InProgress;
 
   let x = props.x;
   let y = props.y;
   let w = props.width;
   let h = props.height;
   let text_x = x + w / 2.0;
-  let text_y = y + h / 2.0;
+  // Centre the block of title lines vertically.
+  let first_line_y = y + h / 2.0 - (props.lines.len() as f64 - 1.0) * props.line_height / 2.0;
+  let line_height = props.line_height;
   let checkmark_x = x + w - 10.0;
   let checkmark_y = y + 10.0;
 
   view! {
       <g
           class=class_attr
-          data-topic-id=props.id
-          on:click=move |_| props.on_click.call(props.id)
+          data-topic-id=id
+          on:click=move |_| props.on_click.call(id)
           style=cursor_style
       >
           <rect
@@ -82,7 +106,7 @@ pub fn RoadmapNode(props: NodeData) -> impl IntoView {
           />
 
           // InProgress: left accent bar
-          {show_accent.then(|| view! {
+          {move || show_accent().then(|| view! {
               <rect
                   x=x
                   y=y
@@ -94,18 +118,20 @@ pub fn RoadmapNode(props: NodeData) -> impl IntoView {
               />
           })}
 
-          <text
-              x=text_x
-              y=text_y
-              class="node-text"
-              text-anchor="middle"
-              dominant-baseline="central"
-          >
-              {props.title}
+          <text class="node-text" text-anchor="middle" dominant-baseline="central">
+              {props
+                  .lines
+                  .into_iter()
+                  .enumerate()
+                  .map(|(i, line)| {
+                      let line_y = first_line_y + i as f64 * line_height;
+                      view! { <tspan x=text_x y=line_y>{line}</tspan> }
+                  })
+                  .collect_view()}
           </text>
 
           // Done: checkmark icon in top-right corner
-          {show_checkmark.then(|| view! {
+          {move || show_checkmark().then(|| view! {
               <text
                   x=checkmark_x
                   y=checkmark_y
@@ -1,9 +1,28 @@
 use crate::components::ui::progress_bar::ProgressBar;
+use crate::data::{Preset, ROADMAPS, Roadmap};
+use crate::layout::tree::LayoutMode;
 use leptos::wasm_bindgen::JsCast;
 use leptos::*;
 
 #[component]
-pub fn Header(search_term: ReadSignal<String>, on_search: Callback<String>) -> impl IntoView {
+pub fn Header(
+  /// The roadmap on the page, selected in the switcher.
+  roadmap: Roadmap,
+  /// Called with the built-in roadmap picked in the switcher.
+  on_switch: Callback<Roadmap>,
+  /// The role preset applied to `roadmap`, if any.
+  preset: Option<&'static Preset>,
+  /// Called with the preset picked, or `None` for every section.
+  on_preset: Callback<Option<&'static Preset>>,
+  /// How the diagram is laid out.
+  layout_mode: Signal<LayoutMode>,
+  /// Called with the layout mode picked.
+  on_layout_mode: Callback<LayoutMode>,
+  search_term: ReadSignal<String>,
+  on_search: Callback<String>,
+  /// Called with the roadmap document the user picks.
+  on_open_file: Callback<web_sys::File>,
+) -> impl IntoView {
   let (is_scrolled, set_is_scrolled) = create_signal(false);
 
   create_effect(move |_| {
@@ -31,6 +50,31 @@ pub fn Header(search_term: ReadSignal<String>, on_search: Callback<String>) -> i
     on_search.call(value);
   };
 
+  let handle_switch = move |ev| {
+    if let Some(next) = Roadmap::builtin(&event_target_value(&ev)) {
+      on_switch.call(next);
+    }
+  };
+
+  let handle_preset = move |ev| on_preset.call(Preset::find(&event_target_value(&ev)));
+  let presets: Vec<&'static Preset> = roadmap.presets().collect();
+  let preset_id = preset.map(|p| p.id);
+
+  let handle_layout_mode = move |ev| {
+    if let Some(mode) = LayoutMode::from_name(&event_target_value(&ev)) {
+      on_layout_mode.call(mode);
+    }
+  };
+
+  let handle_file = move |ev: ev::Event| {
+    let input: web_sys::HtmlInputElement = event_target(&ev);
+    if let Some(file) = input.files().and_then(|files| files.get(0)) {
+      on_open_file.call(file);
+    }
+    // Let the same file be picked again after editing it.
+    input.set_value("");
+  };
+
   view! {
       <header class=move || {
           if is_scrolled.get() {
@@ -51,6 +95,51 @@ pub fn Header(search_term: ReadSignal<String>, on_search: Callback<String>) -> i
               </div>
 
               <div class="header__actions">
+                  <select class="header__switcher" aria-label="Roadmap" on:change=handle_switch>
+                      // A loaded roadmap isn't one of the built-in ones.
+                      {(!roadmap.is_builtin())
+                          .then(|| view! { <option value="" selected>{roadmap.title}</option> })}
+                      {ROADMAPS
+                          .iter()
+                          .map(|r| {
+                              view! {
+                                  <option value=r.id selected=r.id == roadmap.id>
+                                      {r.title}
+                                  </option>
+                              }
+                          })
+                          .collect_view()}
+                  </select>
+                  {(!presets.is_empty()).then(|| view! {
+                      <select class="header__switcher" aria-label="Role preset" on:change=handle_preset>
+                          <option value="" selected=preset_id.is_none()>"All sections"</option>
+                          {presets
+                              .iter()
+                              .map(|p| {
+                                  view! {
+                                      <option value=p.id selected=preset_id == Some(p.id)>
+                                          {p.title}
+                                      </option>
+                                  }
+                              })
+                              .collect_view()}
+                      </select>
+                  })}
+                  <select class="header__switcher" aria-label="Layout" on:change=handle_layout_mode>
+                      {LayoutMode::ALL
+                          .into_iter()
+                          .map(|mode| {
+                              view! {
+                                  <option
+                                      value=mode.as_str()
+                                      selected=move || layout_mode.get() == mode
+                                  >
+                                      {mode.label()}
+                                  </option>
+                              }
+                          })
+                          .collect_view()}
+                  </select>
                   <div class="header__search">
                       <svg
                           class="header__search-icon"
@@ -74,6 +163,27 @@ pub fn Header(search_term: ReadSignal<String>, on_search: Callback<String>) -> i
                           on:input=handle_input
                       />
                   </div>
+                  <label class="header__open" title="Open a roadmap (.json)">
+                      <svg
+                          width="18"
+                          height="18"
+                          viewBox="0 0 24 24"
+                          fill="none"
+                          stroke="currentColor"
+                          stroke-width="2"
+                          stroke-linecap="round"
+                          stroke-linejoin="round"
+                      >
+                          <path d="M22 19a2 2 0 0 1-2 2H4a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h5l2 3h9a2 2 0 0 1 2 2z"></path>
+                      </svg>
+                      <input
+                          type="file"
+                          accept=".json,application/json"
+                          class="header__open-input"
+                          aria-label="Open a roadmap (.json)"
+                          on:change=handle_file
+                      />
+                  </label>
                   <a
                       href="https://github.com/suradet-ps/rust-roadmap"
                       target="_blank"
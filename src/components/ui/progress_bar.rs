@@ -1,7 +1,8 @@
 //! Global completion progress bar component.
 //!
 //! Reads `RoadmapState` from Leptos context and renders a thin animated bar
-//! together with a "X/Y · Z%" completion label.
+//! together with a "X/Y · Z%" completion label, plus the hours left when the
+//! topics have estimates.
 
 use crate::state::roadmap_state::RoadmapState;
 use leptos::*;
@@ -16,6 +17,8 @@ pub fn ProgressBar() -> impl IntoView {
   let pct = state.completion_pct;
   let count = state.completed_count;
   let total = state.total_topics;
+  let has_estimates = state.total_hours.is_some();
+  let hours_remaining = state.hours_remaining;
 
   view! {
       <div class="progress-row">
@@ -28,6 +31,11 @@ pub fn ProgressBar() -> impl IntoView {
           <span class="progress-label">
               {move || format!("{}/{} · {:.0}%", count.get(), total, pct.get())}
           </span>
+          {has_estimates.then(|| view! {
+              <span class="progress-label progress-label--hours">
+                  {move || format!("{} remaining", hours_remaining.get())}
+              </span>
+          })}
       </div>
   }
 }
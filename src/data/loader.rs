@@ -0,0 +1,246 @@
+//! Roadmaps read from a JSON `RoadmapDocument` at runtime.
+//!
+//! The document is checked with the same `validate_roadmap` rules as the
+//! built-in data; any `Severity::Error` rejects it. Its `id` keys the
+//! reader's progress, so it must not be empty or shared with a built-in
+//! roadmap, and its resource links must be web URLs, since anyone can share
+//! a `?roadmap=<url>` link. Only then is it moved into static storage, once
+//! per distinct document.
+
+use crate::data::Roadmap;
+use crate::data::validate::{Diagnostic, Severity, validate_roadmap};
+use crate::models::owned::{
+  OwnedDependency, OwnedSection, OwnedTopic, OwnedTopicContent, RoadmapDocument,
+};
+use std::cell::RefCell;
+use std::fmt;
+
+/// Diagnostics listed in a `LoadError::Invalid` message before "and N more".
+const MAX_LISTED_DIAGNOSTICS: usize = 3;
+
+thread_local! {
+  /// Documents already moved to static storage, so opening one again reuses
+  /// it instead of leaking another copy.
+  static LOADED: RefCell<Vec<(RoadmapDocument, Roadmap)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Why a document couldn't be loaded.
+#[derive(Debug)]
+pub enum LoadError {
+  /// Not JSON, or not shaped like a `RoadmapDocument`.
+  Parse(serde_json::Error),
+  /// Well-formed, but with broken links, duplicate IDs, missing content, …
+  Invalid(Vec<Diagnostic>),
+  /// The `id` is empty or belongs to a built-in roadmap.
+  InvalidId(String),
+  /// A resource URL that isn't `http://` or `https://`.
+  UnsafeUrl(String),
+}
+
+impl fmt::Display for LoadError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      LoadError::Parse(e) => write!(f, "not a roadmap document: {e}"),
+      LoadError::Invalid(diagnostics) => {
+        let listed: Vec<String> = diagnostics
+          .iter()
+          .take(MAX_LISTED_DIAGNOSTICS)
+          .map(Diagnostic::to_string)
+          .collect();
+        write!(f, "{}", listed.join("; "))?;
+        if diagnostics.len() > MAX_LISTED_DIAGNOSTICS {
+          write!(
+            f,
+            "; and {} more",
+            diagnostics.len() - MAX_LISTED_DIAGNOSTICS
+          )?;
+        }
+        Ok(())
+      }
+      LoadError::InvalidId(id) if id.is_empty() => write!(f, "the roadmap `id` is empty"),
+      LoadError::InvalidId(id) => {
+        write!(f, "the roadmap `id` `{id}` is taken by a built-in roadmap")
+      }
+      LoadError::UnsafeUrl(url) => {
+        write!(
+          f,
+          "resource URL `{url}` must start with http:// or https://"
+        )
+      }
+    }
+  }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Parse and validate a roadmap from JSON.
+pub fn load_json(json: &str) -> Result<Roadmap, LoadError> {
+  let document: RoadmapDocument = serde_json::from_str(json).map_err(LoadError::Parse)?;
+  load_document(document)
+}
+
+/// Validate `document` and move it into static storage.
+pub fn load_document(document: RoadmapDocument) -> Result<Roadmap, LoadError> {
+  if document.id.is_empty() || Roadmap::builtin(&document.id).is_some() {
+    return Err(LoadError::InvalidId(document.id));
+  }
+  if let Some(url) = document
+    .content
+    .iter()
+    .flat_map(|c| &c.resources)
+    .map(|r| &r.url)
+    .find(|url| !is_web_url(url))
+  {
+    return Err(LoadError::UnsafeUrl(url.clone()));
+  }
+
+  let errors: Vec<Diagnostic> = validate_roadmap(
+    &document.sections,
+    &document.topics,
+    &document.dependencies,
+    &document.content,
+  )
+  .into_iter()
+  .filter(|d| d.severity() == Severity::Error)
+  .collect();
+  if !errors.is_empty() {
+    return Err(LoadError::Invalid(errors));
+  }
+
+  Ok(LOADED.with(|loaded| {
+    let mut loaded = loaded.borrow_mut();
+    if let Some((_, roadmap)) = loaded.iter().find(|(d, _)| *d == document) {
+      return *roadmap;
+    }
+    let roadmap = into_static(document.clone());
+    loaded.push((document, roadmap));
+    roadmap
+  }))
+}
+
+/// Whether `url` is an absolute `http` or `https` URL, and so can't run
+/// script when followed.
+fn is_web_url(url: &str) -> bool {
+  let url = url.to_ascii_lowercase();
+  url.starts_with("https://") || url.starts_with("http://")
+}
+
+/// Move a validated `document` into static storage.
+fn into_static(document: RoadmapDocument) -> Roadmap {
+  let mut sections: Vec<_> = document
+    .sections
+    .into_iter()
+    .map(OwnedSection::into_static)
+    .collect();
+  sections.sort_by_key(|s| s.order);
+  let topics: Vec<_> = document
+    .topics
+    .into_iter()
+    .map(OwnedTopic::into_static)
+    .collect();
+  let dependencies: Vec<_> = document
+    .dependencies
+    .into_iter()
+    .map(OwnedDependency::into_static)
+    .collect();
+  let content: Vec<_> = document
+    .content
+    .into_iter()
+    .map(OwnedTopicContent::into_static)
+    .collect();
+
+  Roadmap {
+    id: Box::leak(document.id.into_boxed_str()),
+    title: Box::leak(document.title.into_boxed_str()),
+    sections: sections.leak(),
+    topics: topics.leak(),
+    dependencies: dependencies.leak(),
+    content: content.leak(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// A one-topic document whose resource links to `url`.
+  fn document(id: &str, url: &str) -> String {
+    format!(
+      r#"{{
+        "id": "{id}",
+        "title": "Test",
+        "sections": [{{ "id": "sec", "title": "Section", "order": 1 }}],
+        "topics": [{{ "id": "a", "title": "A", "section_id": "sec", "level": "beginner" }}],
+        "content": [{{
+          "id": "a",
+          "title": "A",
+          "resources": [{{ "label": "Link", "url": "{url}", "badge": "article" }}]
+        }}]
+      }}"#
+    )
+  }
+
+  #[test]
+  fn a_valid_document_loads() {
+    let roadmap = load_json(&document("test_valid", "https://example.com/")).unwrap();
+    assert_eq!(roadmap.id, "test_valid");
+    assert_eq!(roadmap.topics.len(), 1);
+  }
+
+  #[test]
+  fn the_readme_example_loads() {
+    let readme = include_str!("../../README.md");
+    let example = readme
+      .split("### Custom Roadmaps")
+      .nth(1)
+      .and_then(|s| s.split("```json\n").nth(1))
+      .and_then(|s| s.split("```").next())
+      .expect("README has a Custom Roadmaps example");
+    let roadmap = load_json(example).unwrap();
+    assert_eq!(roadmap.id, "acme_onboarding");
+    assert_eq!(roadmap.topics.len(), 2);
+  }
+
+  #[test]
+  fn loading_a_document_again_reuses_it() {
+    let json = document("test_again", "https://example.com/");
+    let first = load_json(&json).unwrap();
+    let second = load_json(&json).unwrap();
+    assert!(std::ptr::eq(first.topics, second.topics));
+  }
+
+  #[test]
+  fn script_urls_are_rejected() {
+    for url in [
+      "javascript:alert(1)",
+      "data:text/html,hi",
+      " https://example.com/",
+    ] {
+      let result = load_json(&document("test_urls", url));
+      assert!(
+        matches!(&result, Err(LoadError::UnsafeUrl(u)) if u == url),
+        "{url}: {result:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn builtin_ids_are_rejected() {
+    let id = Roadmap::primary().id;
+    let result = load_json(&document(id, "https://example.com/"));
+    assert!(matches!(result, Err(LoadError::InvalidId(_))));
+  }
+
+  #[test]
+  fn a_huge_row_is_rejected() {
+    let json = document("test_row", "https://example.com/").replace(
+      r#""level": "beginner""#,
+      r#""level": "beginner", "row": 1000000000"#,
+    );
+    let result = load_json(&json);
+    assert!(
+      matches!(&result, Err(LoadError::Invalid(d)) if matches!(d[..], [Diagnostic::RowOutOfRange { .. }])),
+      "{result:?}"
+    );
+  }
+}
@@ -1,361 +1,132 @@
 //! Application data aggregator.
+//!
+//! Sections, topics and dependencies are authored in `roadmap/*.toml`;
+//! `build.rs` validates them and compiles them into `SECTIONS`, `TOPICS` and
+//! `DEPENDENCIES` below. Each topic's description and resources are authored
+//! in `content/<topic_id>.md` and compiled into `CONTENT` the same way.
+//!
+//! The app offers the roadmaps in `ROADMAPS`, each a subset of those
+//! sections listed in `roadmap/tracks.toml`. `loader` builds a `Roadmap`
+//! from a JSON document at runtime instead.
+//!
+//! `PRESETS` are the role presets of `roadmap/presets.toml`; each built-in
+//! roadmap is also compiled cut down to each of them, in `PRESET_ROADMAPS`.
 
-pub mod sections;
+pub mod loader;
+pub mod validate;
 
-use self::sections::*;
-use crate::models::roadmap::{Dependency, Section, SectionLayout, Topic, TopicContent};
+use crate::models::roadmap::{
+  BadgeKind, Dependency, DependencyKind, Hours, Level, Placement, Resource, Section, SectionLayout,
+  Topic, TopicContent, TopicType,
+};
+use std::collections::HashMap;
+use std::sync::OnceLock;
 
-/// Defines the order of sections (The Spine).
-pub const SECTIONS: &[Section] = &[
-  Section {
-    id: s01_introduction::SECTION_ID,
-    title: "Introduction",
-    order: 1,
-    layout: SectionLayout::Grid { cols: 2 },
-  },
-  Section {
-    id: s02_setup::SECTION_ID,
-    title: "Setup & Tooling",
-    order: 2,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s03_language_basics::SECTION_ID,
-    title: "Language Basics",
-    order: 3,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s04_project_structure::SECTION_ID,
-    title: "Project Structure",
-    order: 4,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s05_advanced_types_traits::SECTION_ID,
-    title: "Advanced Types & Traits",
-    order: 5,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s06_memory_lifetimes::SECTION_ID,
-    title: "Memory & Lifetimes",
-    order: 6,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s07_error_handling_safety::SECTION_ID,
-    title: "Error Handling & Safety",
-    order: 7,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s08_testing_tdd::SECTION_ID,
-    title: "Testing & TDD",
-    order: 8,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s09_concurrency_parallelism::SECTION_ID,
-    title: "Concurrency & Parallelism",
-    order: 9,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s10_asynchronous_rust::SECTION_ID,
-    title: "Async Rust",
-    order: 10,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s11_macros_metaprogramming::SECTION_ID,
-    title: "Macros & Metaprogramming",
-    order: 11,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s12_serialization_data::SECTION_ID,
-    title: "Serialization & Data",
-    order: 12,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s13_networking_io::SECTION_ID,
-    title: "Networking & I/O",
-    order: 13,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s14_databases_orm::SECTION_ID,
-    title: "Databases & ORM",
-    order: 14,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s15_documentation_docs::SECTION_ID,
-    title: "Documentation",
-    order: 15,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s16_debugging_tools::SECTION_ID,
-    title: "Debugging Tools",
-    order: 16,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s17_performance_optimization::SECTION_ID,
-    title: "Performance Optimization",
-    order: 17,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s18_cli_utilities::SECTION_ID,
-    title: "CLI Utilities",
-    order: 18,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s19_web_applications::SECTION_ID,
-    title: "Web Applications",
-    order: 19,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s20_webassembly_wasm::SECTION_ID,
-    title: "WebAssembly (WASM)",
-    order: 20,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s21_gui_desktop::SECTION_ID,
-    title: "GUI & Desktop",
-    order: 21,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s22_embedded_systems::SECTION_ID,
-    title: "Embedded Systems",
-    order: 22,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s23_game_dev_graphics::SECTION_ID,
-    title: "Game Dev & Graphics",
-    order: 23,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s24_cryptography_security::SECTION_ID,
-    title: "Cryptography & Security",
-    order: 24,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s25_unsafe_rust::SECTION_ID,
-    title: "Unsafe Rust",
-    order: 25,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s26_ffi_interop::SECTION_ID,
-    title: "FFI & Interop",
-    order: 26,
-    layout: SectionLayout::List,
-  },
-  Section {
-    id: s27_package_management_deepdive::SECTION_ID,
-    title: "Package Management Deep Dive",
-    order: 27,
-    layout: SectionLayout::List,
-  },
-];
+// Generated: `SECTIONS` (in spine order), `TOPICS`, and `DEPENDENCIES`
+// (section-internal, then spine connections, then cross-links) of the full
+// roadmap, `ROADMAPS`, the built-in roadmaps in switcher order, `PRESETS`
+// and `PRESET_ROADMAPS`.
+include!(concat!(env!("OUT_DIR"), "/roadmap.rs"));
 
-/// Aggregates topics from all modular files.
-pub fn get_all_topics() -> Vec<Topic> {
-  let mut topics = Vec::new();
-  topics.extend(s01_introduction::get_topics());
-  topics.extend(s02_setup::get_topics());
-  topics.extend(s03_language_basics::get_topics());
-  topics.extend(s04_project_structure::get_topics());
-  topics.extend(s05_advanced_types_traits::get_topics());
-  topics.extend(s06_memory_lifetimes::get_topics());
-  topics.extend(s07_error_handling_safety::get_topics());
-  topics.extend(s08_testing_tdd::get_topics());
-  topics.extend(s09_concurrency_parallelism::get_topics());
-  topics.extend(s10_asynchronous_rust::get_topics());
-  topics.extend(s11_macros_metaprogramming::get_topics());
-  topics.extend(s12_serialization_data::get_topics());
-  topics.extend(s13_networking_io::get_topics());
-  topics.extend(s14_databases_orm::get_topics());
-  topics.extend(s15_documentation_docs::get_topics());
-  topics.extend(s16_debugging_tools::get_topics());
-  topics.extend(s17_performance_optimization::get_topics());
-  topics.extend(s18_cli_utilities::get_topics());
-  topics.extend(s19_web_applications::get_topics());
-  topics.extend(s20_webassembly_wasm::get_topics());
-  topics.extend(s21_gui_desktop::get_topics());
-  topics.extend(s22_embedded_systems::get_topics());
-  topics.extend(s23_game_dev_graphics::get_topics());
-  topics.extend(s24_cryptography_security::get_topics());
-  topics.extend(s25_unsafe_rust::get_topics());
-  topics.extend(s26_ffi_interop::get_topics());
-  topics.extend(s27_package_management_deepdive::get_topics());
-  topics
-}
-
-/// Aggregates dependencies from all modular files + Spine connections.
-pub fn get_all_dependencies() -> Vec<Dependency> {
-  let mut deps = Vec::new();
-
-  // 1. Internal Module Dependencies
-  deps.extend(s01_introduction::get_dependencies());
-  deps.extend(s02_setup::get_dependencies());
-  deps.extend(s03_language_basics::get_dependencies());
-  deps.extend(s04_project_structure::get_dependencies());
-  deps.extend(s05_advanced_types_traits::get_dependencies());
-  deps.extend(s06_memory_lifetimes::get_dependencies());
-  deps.extend(s07_error_handling_safety::get_dependencies());
-  deps.extend(s08_testing_tdd::get_dependencies());
-  deps.extend(s09_concurrency_parallelism::get_dependencies());
-  deps.extend(s10_asynchronous_rust::get_dependencies());
-  deps.extend(s11_macros_metaprogramming::get_dependencies());
-  deps.extend(s12_serialization_data::get_dependencies());
-  deps.extend(s13_networking_io::get_dependencies());
-  deps.extend(s14_databases_orm::get_dependencies());
-  deps.extend(s15_documentation_docs::get_dependencies());
-  deps.extend(s16_debugging_tools::get_dependencies());
-  deps.extend(s17_performance_optimization::get_dependencies());
-  deps.extend(s18_cli_utilities::get_dependencies());
-  deps.extend(s19_web_applications::get_dependencies());
-  deps.extend(s20_webassembly_wasm::get_dependencies());
-  deps.extend(s21_gui_desktop::get_dependencies());
-  deps.extend(s22_embedded_systems::get_dependencies());
-  deps.extend(s23_game_dev_graphics::get_dependencies());
-  deps.extend(s24_cryptography_security::get_dependencies());
-  deps.extend(s25_unsafe_rust::get_dependencies());
-  deps.extend(s26_ffi_interop::get_dependencies());
-  deps.extend(s27_package_management_deepdive::get_dependencies());
+// Generated: `CONTENT`, one entry per content file, in file name order.
+include!(concat!(env!("OUT_DIR"), "/content.rs"));
 
-  // 2. Spine Connections (The Backbone)
-  let spine_connections = vec![
-    ("intro", "setup_env"),
-    ("setup_env", "basics"),
-    ("basics", "project_structure_spine"),
-    ("project_structure_spine", "advanced_types_traits_spine"),
-    ("advanced_types_traits_spine", "memory_lifetimes_spine"),
-    ("memory_lifetimes_spine", "error_handling_safety_spine"),
-    ("error_handling_safety_spine", "testing_tdd_spine"),
-    ("testing_tdd_spine", "concurrency_parallelism_spine"),
-    ("concurrency_parallelism_spine", "asynchronous_rust_spine"),
-    ("asynchronous_rust_spine", "macros_metaprogramming_spine"),
-    ("macros_metaprogramming_spine", "serialization_data_spine"),
-    ("serialization_data_spine", "networking_io_spine"),
-    ("networking_io_spine", "databases_orm_spine"),
-    ("databases_orm_spine", "documentation_docs_spine"),
-    ("documentation_docs_spine", "debugging_tools_spine"),
-    ("debugging_tools_spine", "performance_optimization_spine"),
-    ("performance_optimization_spine", "cli_utilities_spine"),
-    ("cli_utilities_spine", "web_applications_spine"),
-    ("web_applications_spine", "webassembly_wasm_spine"),
-    ("webassembly_wasm_spine", "gui_desktop_spine"),
-    ("gui_desktop_spine", "embedded_systems_spine"),
-    ("embedded_systems_spine", "game_dev_graphics_spine"),
-    ("game_dev_graphics_spine", "cryptography_security_spine"),
-    ("cryptography_security_spine", "unsafe_rust_spine"),
-    ("unsafe_rust_spine", "ffi_interop_spine"),
-    ("ffi_interop_spine", "package_management_deepdive_spine"),
-  ];
+/// Every topic's content.
+pub fn all_topic_content() -> impl Iterator<Item = &'static TopicContent> {
+  CONTENT.iter()
+}
 
-  for (from, to) in spine_connections {
-    deps.push(Dependency { from, to });
-  }
+/// Content for topic `id`. The lookup table is built on first use.
+pub fn get_topic_content(id: &str) -> Option<&'static TopicContent> {
+  static REGISTRY: OnceLock<HashMap<&'static str, &'static TopicContent>> = OnceLock::new();
+  REGISTRY
+    .get_or_init(|| all_topic_content().map(|c| (c.id, c)).collect())
+    .get(id)
+    .copied()
+}
 
-  deps
+/// A roadmap the page can show: one of `ROADMAPS`, or one built by `loader`.
+#[derive(Debug, Clone, Copy)]
+pub struct Roadmap {
+  /// Namespaces the reader's progress in `local_storage`.
+  pub id: &'static str,
+  pub title: &'static str,
+  /// In spine order.
+  pub sections: &'static [Section],
+  pub topics: &'static [Topic],
+  pub dependencies: &'static [Dependency],
+  pub content: &'static [TopicContent],
 }
 
-/// Aggregates content lookup from all decentralized content files.
-pub fn get_topic_content(id: &str) -> Option<TopicContent> {
-  if let Some(c) = s01_introduction::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s02_setup::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s03_language_basics::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s04_project_structure::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s05_advanced_types_traits::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s06_memory_lifetimes::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s07_error_handling_safety::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s08_testing_tdd::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s09_concurrency_parallelism::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s10_asynchronous_rust::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s11_macros_metaprogramming::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s12_serialization_data::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s13_networking_io::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s14_databases_orm::content::get_content(id) {
-    return Some(c);
+impl Roadmap {
+  /// The built-in roadmap shown until the reader picks another.
+  pub fn primary() -> Self {
+    ROADMAPS[0]
   }
-  if let Some(c) = s15_documentation_docs::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s16_debugging_tools::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s17_performance_optimization::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s18_cli_utilities::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s19_web_applications::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s20_webassembly_wasm::content::get_content(id) {
-    return Some(c);
-  }
-  if let Some(c) = s21_gui_desktop::content::get_content(id) {
-    return Some(c);
+
+  /// The built-in roadmap with ID `id`.
+  pub fn builtin(id: &str) -> Option<Self> {
+    ROADMAPS.iter().find(|r| r.id == id).copied()
   }
-  if let Some(c) = s22_embedded_systems::content::get_content(id) {
-    return Some(c);
+
+  pub fn is_builtin(&self) -> bool {
+    Roadmap::builtin(self.id).is_some_and(|r| std::ptr::eq(r.topics, self.topics))
   }
-  if let Some(c) = s23_game_dev_graphics::content::get_content(id) {
-    return Some(c);
+
+  /// Content for topic `id`.
+  pub fn topic_content(&self, id: &str) -> Option<&'static TopicContent> {
+    if std::ptr::eq(self.content, CONTENT) {
+      get_topic_content(id)
+    } else {
+      self.content.iter().find(|c| c.id == id)
+    }
   }
-  if let Some(c) = s24_cryptography_security::content::get_content(id) {
-    return Some(c);
+
+  /// This roadmap cut down to preset `preset_id`: only the preset's
+  /// sections, with the spine chained through them. `None` for a loaded
+  /// roadmap, or if it has none of the preset's sections.
+  pub fn with_preset(&self, preset_id: &str) -> Option<Self> {
+    if !self.is_builtin() {
+      return None;
+    }
+    PRESET_ROADMAPS
+      .iter()
+      .find(|p| p.preset_id == preset_id && p.roadmap.id == self.id)
+      .map(|p| p.roadmap)
   }
-  if let Some(c) = s25_unsafe_rust::content::get_content(id) {
-    return Some(c);
+
+  /// The presets this roadmap can be cut down to.
+  pub fn presets(&self) -> impl Iterator<Item = &'static Preset> + '_ {
+    PRESETS.iter().filter(|p| self.with_preset(p.id).is_some())
   }
-  if let Some(c) = s26_ffi_interop::content::get_content(id) {
-    return Some(c);
+
+  /// The section topic `id` belongs to.
+  pub fn section_of(&self, id: &str) -> Option<&'static Section> {
+    let topic = self.topics.iter().find(|t| t.id == id)?;
+    self.sections.iter().find(|s| s.id == topic.section_id)
   }
-  if let Some(c) = s27_package_management_deepdive::content::get_content(id) {
-    return Some(c);
+}
+
+/// A role preset: the sections one kind of reader needs, e.g. Backend.
+#[derive(Debug, Clone, Copy)]
+pub struct Preset {
+  pub id: &'static str,
+  pub title: &'static str,
+  /// Section IDs, in roadmap order.
+  pub sections: &'static [&'static str],
+}
+
+/// A built-in roadmap cut down to one preset.
+#[derive(Debug)]
+pub struct PresetRoadmap {
+  pub preset_id: &'static str,
+  /// Keeps the full roadmap's `id`, so progress is shared with it.
+  pub roadmap: Roadmap,
+}
+
+impl Preset {
+  /// The preset with ID `id`.
+  pub fn find(id: &str) -> Option<&'static Preset> {
+    PRESETS.iter().find(|p| p.id == id)
   }
-  None
 }
@@ -1,27 +0,0 @@
-pub mod s01_introduction;
-pub mod s02_setup;
-pub mod s03_language_basics;
-pub mod s04_project_structure;
-pub mod s05_advanced_types_traits;
-pub mod s06_memory_lifetimes;
-pub mod s07_error_handling_safety;
-pub mod s08_testing_tdd;
-pub mod s09_concurrency_parallelism;
-pub mod s10_asynchronous_rust;
-pub mod s11_macros_metaprogramming;
-pub mod s12_serialization_data;
-pub mod s13_networking_io;
-pub mod s14_databases_orm;
-pub mod s15_documentation_docs;
-pub mod s16_debugging_tools;
-pub mod s17_performance_optimization;
-pub mod s18_cli_utilities;
-pub mod s19_web_applications;
-pub mod s20_webassembly_wasm;
-pub mod s21_gui_desktop;
-pub mod s22_embedded_systems;
-pub mod s23_game_dev_graphics;
-pub mod s24_cryptography_security;
-pub mod s25_unsafe_rust;
-pub mod s26_ffi_interop;
-pub mod s27_package_management_deepdive;
@@ -0,0 +1,597 @@
+//! Consistency checks for the roadmap data.
+//!
+//! `build.rs` runs `validate_roadmap` over the compiled roadmap files and
+//! fails the build on any `Severity::Error`, so broken links, IDs or
+//! learning orders never reach the app. `data::loader` applies the same
+//! checks to roadmaps loaded at runtime.
+
+use crate::models::owned::{OwnedDependency, OwnedSection, OwnedTopic, OwnedTopicContent};
+use crate::models::roadmap::{Dependency, DependencyKind, Section, Topic, TopicContent};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Characters the `local_storage` format uses as separators.
+const RESERVED_ID_CHARS: &[char] = &[':', '|'];
+
+/// How serious a `Diagnostic` is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+  /// The data is broken; the build fails.
+  Error,
+  /// Suspicious but renderable; reported as a build warning.
+  Warning,
+}
+
+/// One problem found in the roadmap data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+  /// Two sections share an ID.
+  DuplicateSectionId { id: String },
+  /// Two topics share an ID.
+  DuplicateTopicId { id: String },
+  /// A topic has the same ID as a section. Edges into a collapsed section
+  /// end at its ID, so the two would be confused.
+  TopicIdIsSectionId { id: String },
+  /// A topic names a section that isn't in `SECTIONS`.
+  UnknownSection { topic: String, section: String },
+  /// A dependency endpoint names no topic.
+  UnknownDependencyEndpoint {
+    from: String,
+    to: String,
+    missing: String,
+  },
+  /// A section or topic ID contains a character reserved by the storage format.
+  ReservedCharInId { id: String, ch: char },
+  /// A topic's `row` is past the last row its section could have, one per
+  /// topic.
+  RowOutOfRange {
+    topic: String,
+    row: usize,
+    max: usize,
+  },
+  /// Topics that depend on each other in a loop of ordered (not `Related`)
+  /// dependencies.
+  DependencyCycle { topics: Vec<String> },
+  /// A topic has no `TopicContent`, so its drawer can't open.
+  MissingContent { topic: String },
+  /// `TopicContent` for an ID that isn't a topic.
+  OrphanContent { id: String },
+}
+
+impl Diagnostic {
+  pub fn severity(&self) -> Severity {
+    match self {
+      // Unused content is harmless.
+      Diagnostic::OrphanContent { .. } => Severity::Warning,
+      _ => Severity::Error,
+    }
+  }
+}
+
+impl fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Diagnostic::DuplicateSectionId { id } => write!(f, "duplicate section id `{id}`"),
+      Diagnostic::DuplicateTopicId { id } => write!(f, "duplicate topic id `{id}`"),
+      Diagnostic::TopicIdIsSectionId { id } => {
+        write!(f, "`{id}` names both a topic and a section")
+      }
+      Diagnostic::UnknownSection { topic, section } => {
+        write!(f, "topic `{topic}` is in unknown section `{section}`")
+      }
+      Diagnostic::UnknownDependencyEndpoint { from, to, missing } => {
+        write!(
+          f,
+          "dependency `{from}` -> `{to}` names unknown topic `{missing}`"
+        )
+      }
+      Diagnostic::ReservedCharInId { id, ch } => {
+        write!(
+          f,
+          "id `{id}` contains `{ch}`, which local storage uses as a separator"
+        )
+      }
+      Diagnostic::RowOutOfRange { topic, row, max } => {
+        write!(
+          f,
+          "topic `{topic}` is in row {row}, but its section has rows 0 to {max}"
+        )
+      }
+      Diagnostic::DependencyCycle { topics } => {
+        write!(f, "dependency cycle through `{}`", topics.join("`, `"))
+      }
+      Diagnostic::MissingContent { topic } => write!(f, "topic `{topic}` has no content"),
+      Diagnostic::OrphanContent { id } => write!(f, "content `{id}` belongs to no topic"),
+    }
+  }
+}
+
+// What `validate_roadmap` reads from each item. Implemented for the models
+// and for their owned variants, so a loaded document is checked before it
+// is moved to static storage.
+
+pub trait SectionFields {
+  fn id(&self) -> &str;
+}
+
+pub trait TopicFields {
+  fn id(&self) -> &str;
+  fn section_id(&self) -> &str;
+  fn row(&self) -> Option<usize>;
+}
+
+pub trait DependencyFields {
+  fn from(&self) -> &str;
+  fn to(&self) -> &str;
+  fn kind(&self) -> DependencyKind;
+}
+
+pub trait ContentFields {
+  fn id(&self) -> &str;
+}
+
+impl SectionFields for Section {
+  fn id(&self) -> &str {
+    self.id
+  }
+}
+
+impl SectionFields for OwnedSection {
+  fn id(&self) -> &str {
+    &self.id
+  }
+}
+
+impl TopicFields for Topic {
+  fn id(&self) -> &str {
+    self.id
+  }
+  fn section_id(&self) -> &str {
+    self.section_id
+  }
+  fn row(&self) -> Option<usize> {
+    self.row
+  }
+}
+
+impl TopicFields for OwnedTopic {
+  fn id(&self) -> &str {
+    &self.id
+  }
+  fn section_id(&self) -> &str {
+    &self.section_id
+  }
+  fn row(&self) -> Option<usize> {
+    self.row
+  }
+}
+
+impl DependencyFields for Dependency {
+  fn from(&self) -> &str {
+    self.from
+  }
+  fn to(&self) -> &str {
+    self.to
+  }
+  fn kind(&self) -> DependencyKind {
+    self.kind
+  }
+}
+
+impl DependencyFields for OwnedDependency {
+  fn from(&self) -> &str {
+    &self.from
+  }
+  fn to(&self) -> &str {
+    &self.to
+  }
+  fn kind(&self) -> DependencyKind {
+    self.kind
+  }
+}
+
+impl ContentFields for TopicContent {
+  fn id(&self) -> &str {
+    self.id
+  }
+}
+
+impl ContentFields for OwnedTopicContent {
+  fn id(&self) -> &str {
+    &self.id
+  }
+}
+
+/// Check `sections`, `topics`, `dependencies` and `content` against each other.
+/// Returns every problem found, in a stable order.
+pub fn validate_roadmap<'a, C: ContentFields + 'a>(
+  sections: &[impl SectionFields],
+  topics: &[impl TopicFields],
+  dependencies: &[impl DependencyFields],
+  content: impl IntoIterator<Item = &'a C>,
+) -> Vec<Diagnostic> {
+  let mut diagnostics = Vec::new();
+
+  // IDs: unique and free of storage separators.
+  let mut section_ids = HashSet::new();
+  for s in sections {
+    if !section_ids.insert(s.id()) {
+      diagnostics.push(Diagnostic::DuplicateSectionId { id: s.id().into() });
+    }
+    check_id_chars(s.id(), &mut diagnostics);
+  }
+  let mut topic_ids = HashSet::new();
+  let mut section_sizes: HashMap<&str, usize> = HashMap::new();
+  for t in topics {
+    if !topic_ids.insert(t.id()) {
+      diagnostics.push(Diagnostic::DuplicateTopicId { id: t.id().into() });
+    }
+    if section_ids.contains(t.id()) {
+      diagnostics.push(Diagnostic::TopicIdIsSectionId { id: t.id().into() });
+    }
+    check_id_chars(t.id(), &mut diagnostics);
+    if !section_ids.contains(t.section_id()) {
+      diagnostics.push(Diagnostic::UnknownSection {
+        topic: t.id().into(),
+        section: t.section_id().into(),
+      });
+    }
+    *section_sizes.entry(t.section_id()).or_default() += 1;
+  }
+
+  // Row hints: a grid never needs more rows than its section has topics,
+  // and the layout allocates every row up to the hint.
+  for t in topics {
+    let max = section_sizes[t.section_id()] - 1;
+    if let Some(row) = t.row().filter(|&row| row > max) {
+      diagnostics.push(Diagnostic::RowOutOfRange {
+        topic: t.id().into(),
+        row,
+        max,
+      });
+    }
+  }
+
+  // Dependencies: both ends exist.
+  for d in dependencies {
+    for missing in [d.from(), d.to()] {
+      if !topic_ids.contains(missing) {
+        diagnostics.push(Diagnostic::UnknownDependencyEndpoint {
+          from: d.from().into(),
+          to: d.to().into(),
+          missing: missing.into(),
+        });
+      }
+    }
+  }
+  for topics in dependency_cycles(topics, dependencies) {
+    diagnostics.push(Diagnostic::DependencyCycle { topics });
+  }
+
+  // Content: one entry per topic.
+  let content_ids: HashSet<&str> = content.into_iter().map(|c| c.id()).collect();
+  for t in topics {
+    if !content_ids.contains(t.id()) {
+      diagnostics.push(Diagnostic::MissingContent {
+        topic: t.id().into(),
+      });
+    }
+  }
+  let mut orphans: Vec<_> = content_ids.difference(&topic_ids).copied().collect();
+  orphans.sort_unstable();
+  diagnostics.extend(
+    orphans
+      .into_iter()
+      .map(|id| Diagnostic::OrphanContent { id: id.into() }),
+  );
+
+  diagnostics
+}
+
+fn check_id_chars(id: &str, diagnostics: &mut Vec<Diagnostic>) {
+  if let Some(ch) = id.chars().find(|c| RESERVED_ID_CHARS.contains(c)) {
+    diagnostics.push(Diagnostic::ReservedCharInId { id: id.into(), ch });
+  }
+}
+
+/// Strongly connected components of the graph of ordered dependencies that
+/// contain a loop (Tarjan's algorithm), each listed in topic order.
+fn dependency_cycles(
+  topics: &[impl TopicFields],
+  dependencies: &[impl DependencyFields],
+) -> Vec<Vec<String>> {
+  let index_of: HashMap<&str, usize> = topics
+    .iter()
+    .enumerate()
+    .map(|(i, t)| (t.id(), i))
+    .collect();
+  let mut next: Vec<Vec<usize>> = vec![Vec::new(); topics.len()];
+  for d in dependencies.iter().filter(|d| d.kind().is_ordered()) {
+    if let (Some(&a), Some(&b)) = (index_of.get(d.from()), index_of.get(d.to())) {
+      next[a].push(b);
+    }
+  }
+
+  let n = topics.len();
+  let mut counter = 0;
+  let mut index: Vec<Option<usize>> = vec![None; n];
+  let mut low = vec![0; n];
+  let mut stack = Vec::new();
+  let mut on_stack = vec![false; n];
+  let mut components: Vec<Vec<usize>> = Vec::new();
+
+  for root in 0..n {
+    if index[root].is_some() {
+      continue;
+    }
+    // Iterative DFS so a long chain can't overflow the call stack:
+    // (node, next successor slot).
+    let mut path = vec![(root, 0usize)];
+    index[root] = Some(counter);
+    low[root] = counter;
+    counter += 1;
+    stack.push(root);
+    on_stack[root] = true;
+
+    while let Some(top) = path.last_mut() {
+      let v = top.0;
+      if let Some(&w) = next[v].get(top.1) {
+        top.1 += 1;
+        match index[w] {
+          None => {
+            index[w] = Some(counter);
+            low[w] = counter;
+            counter += 1;
+            stack.push(w);
+            on_stack[w] = true;
+            path.push((w, 0));
+          }
+          Some(iw) if on_stack[w] => low[v] = low[v].min(iw),
+          Some(_) => {}
+        }
+        continue;
+      }
+
+      path.pop();
+      if let Some(&(parent, _)) = path.last() {
+        low[parent] = low[parent].min(low[v]);
+      }
+      if Some(low[v]) == index[v] {
+        let mut component = Vec::new();
+        while let Some(w) = stack.pop() {
+          on_stack[w] = false;
+          component.push(w);
+          if w == v {
+            break;
+          }
+        }
+        components.push(component);
+      }
+    }
+  }
+
+  let mut cycles: Vec<Vec<usize>> = components
+    .into_iter()
+    .filter(|c| c.len() > 1 || next[c[0]].contains(&c[0]))
+    .map(|mut c| {
+      c.sort_unstable();
+      c
+    })
+    .collect();
+  cycles.sort_unstable();
+  cycles
+    .into_iter()
+    .map(|c| c.into_iter().map(|i| topics[i].id().into()).collect())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::models::roadmap::{DependencyKind, Level, Placement, SectionLayout, TopicType};
+
+  fn section(id: &'static str) -> Section {
+    Section {
+      id,
+      title: id,
+      order: 1,
+      layout: SectionLayout::List,
+    }
+  }
+
+  fn topic(id: &'static str) -> Topic {
+    Topic {
+      id,
+      title: id,
+      section_id: "sec",
+      level: Level::Beginner,
+      topic_type: TopicType::Sub,
+      placement: Placement::Center,
+      row: None,
+      estimated_hours: None,
+    }
+  }
+
+  fn dep(from: &'static str, to: &'static str, kind: DependencyKind) -> Dependency {
+    Dependency {
+      from,
+      to,
+      kind,
+      label: None,
+    }
+  }
+
+  fn content(id: &'static str) -> TopicContent {
+    TopicContent {
+      id,
+      title: id,
+      description: "",
+      resources: &[],
+    }
+  }
+
+  /// Diagnostics for `topics` and `dependencies` in one section, with
+  /// content for every topic.
+  fn check(topics: &[Topic], dependencies: &[Dependency]) -> Vec<Diagnostic> {
+    let content: Vec<TopicContent> = topics.iter().map(|t| content(t.id)).collect();
+    validate_roadmap(&[section("sec")], topics, dependencies, &content)
+  }
+
+  #[test]
+  fn consistent_data_passes() {
+    let deps = [
+      dep("a", "b", DependencyKind::Prerequisite),
+      dep("b", "a", DependencyKind::Related),
+    ];
+    assert_eq!(check(&[topic("a"), topic("b")], &deps), vec![]);
+  }
+
+  #[test]
+  fn a_topic_named_like_a_section_is_an_error() {
+    let diagnostics = check(&[topic("a"), topic("sec")], &[]);
+    assert_eq!(
+      diagnostics,
+      vec![Diagnostic::TopicIdIsSectionId { id: "sec".into() }]
+    );
+    assert_eq!(diagnostics[0].severity(), Severity::Error);
+  }
+
+  #[test]
+  fn an_unknown_endpoint_is_an_error() {
+    let diagnostics = check(
+      &[topic("a")],
+      &[dep("a", "b", DependencyKind::Prerequisite)],
+    );
+    assert_eq!(
+      diagnostics,
+      vec![Diagnostic::UnknownDependencyEndpoint {
+        from: "a".into(),
+        to: "b".into(),
+        missing: "b".into(),
+      }]
+    );
+    assert_eq!(diagnostics[0].severity(), Severity::Error);
+  }
+
+  #[test]
+  fn a_duplicate_topic_id_is_an_error() {
+    let diagnostics = check(&[topic("a"), topic("a")], &[]);
+    assert_eq!(
+      diagnostics,
+      vec![Diagnostic::DuplicateTopicId { id: "a".into() }]
+    );
+    assert_eq!(diagnostics[0].severity(), Severity::Error);
+  }
+
+  #[test]
+  fn a_duplicate_section_id_is_an_error() {
+    let diagnostics = validate_roadmap::<TopicContent>(
+      &[section("sec"), section("sec")],
+      &[] as &[Topic],
+      &[] as &[Dependency],
+      &[],
+    );
+    assert_eq!(
+      diagnostics,
+      vec![Diagnostic::DuplicateSectionId { id: "sec".into() }]
+    );
+  }
+
+  #[test]
+  fn a_storage_separator_in_an_id_is_an_error() {
+    let diagnostics = check(&[topic("a:b")], &[]);
+    assert_eq!(
+      diagnostics,
+      vec![Diagnostic::ReservedCharInId {
+        id: "a:b".into(),
+        ch: ':'
+      }]
+    );
+    assert_eq!(diagnostics[0].severity(), Severity::Error);
+  }
+
+  #[test]
+  fn a_row_past_the_section_is_an_error() {
+    let far = Topic {
+      row: Some(2),
+      ..topic("b")
+    };
+    let diagnostics = check(&[topic("a"), far], &[]);
+    assert_eq!(
+      diagnostics,
+      vec![Diagnostic::RowOutOfRange {
+        topic: "b".into(),
+        row: 2,
+        max: 1,
+      }]
+    );
+    assert_eq!(diagnostics[0].severity(), Severity::Error);
+  }
+
+  #[test]
+  fn a_cycle_of_ordered_links_is_an_error() {
+    let deps = [
+      dep("a", "b", DependencyKind::Prerequisite),
+      dep("b", "c", DependencyKind::Recommended),
+      dep("c", "a", DependencyKind::Prerequisite),
+    ];
+    let diagnostics = check(&[topic("a"), topic("b"), topic("c"), topic("d")], &deps);
+    assert_eq!(
+      diagnostics,
+      vec![Diagnostic::DependencyCycle {
+        topics: vec!["a".into(), "b".into(), "c".into()],
+      }]
+    );
+    assert_eq!(diagnostics[0].severity(), Severity::Error);
+  }
+
+  #[test]
+  fn a_self_loop_is_a_cycle() {
+    let diagnostics = check(
+      &[topic("a")],
+      &[dep("a", "a", DependencyKind::Prerequisite)],
+    );
+    assert_eq!(
+      diagnostics,
+      vec![Diagnostic::DependencyCycle {
+        topics: vec!["a".into()]
+      }]
+    );
+  }
+
+  #[test]
+  fn a_long_chain_does_not_overflow_the_stack() {
+    const LEN: usize = 200_000;
+    let ids: Vec<&'static str> = (0..LEN).map(|i| format!("t{i}").leak() as &str).collect();
+    let topics: Vec<Topic> = ids.iter().map(|&id| topic(id)).collect();
+    let mut deps: Vec<Dependency> = ids
+      .windows(2)
+      .map(|w| dep(w[0], w[1], DependencyKind::Prerequisite))
+      .collect();
+    assert!(dependency_cycles(&topics, &deps).is_empty());
+
+    deps.push(dep(ids[LEN - 1], ids[0], DependencyKind::Prerequisite));
+    let cycles = dependency_cycles(&topics, &deps);
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(cycles[0].len(), LEN);
+  }
+
+  #[test]
+  fn content_must_match_the_topics() {
+    let diagnostics = validate_roadmap(
+      &[section("sec")],
+      &[topic("a")],
+      &[] as &[Dependency],
+      &[content("b")],
+    );
+    assert_eq!(
+      diagnostics,
+      vec![
+        Diagnostic::MissingContent { topic: "a".into() },
+        Diagnostic::OrphanContent { id: "b".into() },
+      ]
+    );
+    assert_eq!(diagnostics[0].severity(), Severity::Error);
+    assert_eq!(diagnostics[1].severity(), Severity::Warning);
+  }
+}
@@ -0,0 +1,288 @@
+//! Layered (Sugiyama-style) ordering for the topics of one section.
+//!
+//! 1. Break cycles by dropping DFS back edges.
+//! 2. Rank each topic by its longest prerequisite chain.
+//! 3. Reorder every layer with barycenter sweeps, keeping the ordering
+//!    with the fewest crossings between adjacent layers.
+
+use crate::models::roadmap::{Dependency, Topic};
+use std::collections::HashMap;
+
+/// Number of down + up barycenter sweeps.
+const SWEEPS: usize = 4;
+
+/// Split `topics` into layers of indices into `topics`, top layer first.
+///
+/// Only ordered dependencies between two topics of the slice are considered.
+/// The initial order within a layer follows the slice order.
+pub fn layers(topics: &[&Topic], dependencies: &[Dependency]) -> Vec<Vec<usize>> {
+  let n = topics.len();
+  if n == 0 {
+    return Vec::new();
+  }
+
+  let index: HashMap<&str, usize> = topics.iter().enumerate().map(|(i, t)| (t.id, i)).collect();
+
+  let mut succs: Vec<Vec<usize>> = vec![Vec::new(); n];
+  for dep in dependencies.iter().filter(|d| d.kind.is_ordered()) {
+    if let (Some(&from), Some(&to)) = (index.get(dep.from), index.get(dep.to))
+      && from != to
+      && !succs[from].contains(&to)
+    {
+      succs[from].push(to);
+    }
+  }
+
+  let succs = drop_back_edges(&succs);
+  let mut preds: Vec<Vec<usize>> = vec![Vec::new(); n];
+  for (from, targets) in succs.iter().enumerate() {
+    for &to in targets {
+      preds[to].push(from);
+    }
+  }
+
+  let rank = longest_path_ranks(&succs, &preds);
+  let depth = rank.iter().max().map_or(0, |r| r + 1);
+  let mut layers: Vec<Vec<usize>> = vec![Vec::new(); depth];
+  for (i, &r) in rank.iter().enumerate() {
+    layers[r].push(i);
+  }
+
+  reduce_crossings(&mut layers, &succs, &preds);
+  layers
+}
+
+/// Remove the edges that close a cycle, visiting nodes in slice order.
+fn drop_back_edges(succs: &[Vec<usize>]) -> Vec<Vec<usize>> {
+  #[derive(Clone, Copy, PartialEq)]
+  enum Mark {
+    New,
+    Active,
+    Done,
+  }
+
+  let n = succs.len();
+  let mut mark = vec![Mark::New; n];
+  let mut kept: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+  for root in 0..n {
+    if mark[root] != Mark::New {
+      continue;
+    }
+    // Iterative DFS: (node, next successor slot).
+    let mut stack = vec![(root, 0usize)];
+    mark[root] = Mark::Active;
+    while let Some(top) = stack.last_mut() {
+      let node = top.0;
+      if let Some(&to) = succs[node].get(top.1) {
+        top.1 += 1;
+        match mark[to] {
+          Mark::Active => {} // back edge — dropped
+          Mark::Done => kept[node].push(to),
+          Mark::New => {
+            kept[node].push(to);
+            mark[to] = Mark::Active;
+            stack.push((to, 0));
+          }
+        }
+      } else {
+        mark[node] = Mark::Done;
+        stack.pop();
+      }
+    }
+  }
+
+  kept
+}
+
+/// Rank = length of the longest chain of predecessors (Kahn order on a DAG).
+fn longest_path_ranks(succs: &[Vec<usize>], preds: &[Vec<usize>]) -> Vec<usize> {
+  let n = succs.len();
+  let mut rank = vec![0usize; n];
+  let mut in_degree: Vec<usize> = preds.iter().map(Vec::len).collect();
+  let mut queue: Vec<usize> = (0..n).filter(|&i| in_degree[i] == 0).collect();
+
+  while let Some(node) = queue.pop() {
+    for &to in &succs[node] {
+      rank[to] = rank[to].max(rank[node] + 1);
+      in_degree[to] -= 1;
+      if in_degree[to] == 0 {
+        queue.push(to);
+      }
+    }
+  }
+
+  rank
+}
+
+/// Alternate down and up barycenter sweeps, keeping the best ordering seen.
+fn reduce_crossings(layers: &mut [Vec<usize>], succs: &[Vec<usize>], preds: &[Vec<usize>]) {
+  let mut best = layers.to_vec();
+  let mut best_crossings = total_crossings(layers, succs);
+
+  for _ in 0..SWEEPS {
+    if best_crossings == 0 {
+      break;
+    }
+    for i in 1..layers.len() {
+      let (above, rest) = layers.split_at_mut(i);
+      sort_by_barycenter(&mut rest[0], &above[i - 1], preds);
+    }
+    for i in (0..layers.len().saturating_sub(1)).rev() {
+      let (head, below) = layers.split_at_mut(i + 1);
+      sort_by_barycenter(&mut head[i], &below[0], succs);
+    }
+
+    let crossings = total_crossings(layers, succs);
+    if crossings < best_crossings {
+      best_crossings = crossings;
+      best = layers.to_vec();
+    }
+  }
+
+  layers.clone_from_slice(&best);
+}
+
+/// Order `layer` by the mean position of each node's neighbours in `fixed`.
+/// Nodes without neighbours there keep their current position.
+fn sort_by_barycenter(layer: &mut [usize], fixed: &[usize], neighbours: &[Vec<usize>]) {
+  let position: HashMap<usize, usize> = fixed.iter().enumerate().map(|(p, &n)| (n, p)).collect();
+
+  let mut keyed: Vec<(f64, usize)> = layer
+    .iter()
+    .enumerate()
+    .map(|(current, &node)| {
+      let placed: Vec<usize> = neighbours[node]
+        .iter()
+        .filter_map(|n| position.get(n).copied())
+        .collect();
+      let key = if placed.is_empty() {
+        current as f64
+      } else {
+        placed.iter().sum::<usize>() as f64 / placed.len() as f64
+      };
+      (key, node)
+    })
+    .collect();
+
+  keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+  for (slot, (_, node)) in layer.iter_mut().zip(keyed) {
+    *slot = node;
+  }
+}
+
+/// Crossings between every pair of adjacent layers.
+fn total_crossings(layers: &[Vec<usize>], succs: &[Vec<usize>]) -> usize {
+  layers
+    .windows(2)
+    .map(|pair| crossings_between(&pair[0], &pair[1], succs))
+    .sum()
+}
+
+/// Count pairs of edges `upper → lower` whose endpoints are in opposite order.
+fn crossings_between(upper: &[usize], lower: &[usize], succs: &[Vec<usize>]) -> usize {
+  let position: HashMap<usize, usize> = lower.iter().enumerate().map(|(p, &n)| (n, p)).collect();
+
+  let edges: Vec<(usize, usize)> = upper
+    .iter()
+    .enumerate()
+    .flat_map(|(top, &node)| {
+      succs[node]
+        .iter()
+        .filter_map(|to| position.get(to).map(|&bottom| (top, bottom)))
+        .collect::<Vec<_>>()
+    })
+    .collect();
+
+  let mut count = 0;
+  for (i, a) in edges.iter().enumerate() {
+    for b in &edges[i + 1..] {
+      if (a.0 < b.0 && a.1 > b.1) || (a.0 > b.0 && a.1 < b.1) {
+        count += 1;
+      }
+    }
+  }
+  count
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::models::roadmap::{DependencyKind, Level, Placement, TopicType};
+
+  fn topic(id: &'static str) -> Topic {
+    Topic {
+      id,
+      title: id,
+      section_id: "sec",
+      level: Level::Beginner,
+      topic_type: TopicType::Sub,
+      placement: Placement::Center,
+      row: None,
+      estimated_hours: None,
+    }
+  }
+
+  fn dep(from: &'static str, to: &'static str, kind: DependencyKind) -> Dependency {
+    Dependency {
+      from,
+      to,
+      kind,
+      label: None,
+    }
+  }
+
+  fn layers_of(ids: &[&'static str], dependencies: &[Dependency]) -> Vec<Vec<usize>> {
+    let topics: Vec<Topic> = ids.iter().map(|&id| topic(id)).collect();
+    let refs: Vec<&Topic> = topics.iter().collect();
+    layers(&refs, dependencies)
+  }
+
+  #[test]
+  fn a_chain_gets_one_layer_per_topic() {
+    let deps = [
+      dep("a", "b", DependencyKind::Prerequisite),
+      dep("b", "c", DependencyKind::Recommended),
+    ];
+    assert_eq!(
+      layers_of(&["a", "b", "c"], &deps),
+      vec![vec![0], vec![1], vec![2]]
+    );
+  }
+
+  #[test]
+  fn a_cycle_still_yields_layers() {
+    let deps = [
+      dep("a", "b", DependencyKind::Prerequisite),
+      dep("b", "a", DependencyKind::Prerequisite),
+      dep("b", "c", DependencyKind::Prerequisite),
+    ];
+    assert_eq!(
+      layers_of(&["a", "b", "c"], &deps),
+      vec![vec![0], vec![1], vec![2]]
+    );
+  }
+
+  #[test]
+  fn related_links_do_not_order_topics() {
+    let deps = [dep("a", "b", DependencyKind::Related)];
+    assert_eq!(layers_of(&["a", "b"], &deps), vec![vec![0, 1]]);
+  }
+
+  #[test]
+  fn crossing_edges_are_untangled() {
+    let deps = [
+      dep("a", "d", DependencyKind::Prerequisite),
+      dep("b", "c", DependencyKind::Prerequisite),
+    ];
+    let layers = layers_of(&["a", "b", "c", "d"], &deps);
+    assert_eq!(layers, vec![vec![0, 1], vec![3, 2]]);
+  }
+
+  #[test]
+  fn crossings_are_counted_between_layers() {
+    let succs = vec![vec![3], vec![2], vec![], vec![]];
+    assert_eq!(crossings_between(&[0, 1], &[2, 3], &succs), 1);
+    assert_eq!(crossings_between(&[0, 1], &[3, 2], &succs), 0);
+  }
+}
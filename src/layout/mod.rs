@@ -1 +1,5 @@
+pub mod layered;
+pub mod routing;
+pub mod spatial;
+pub mod text;
 pub mod tree;
@@ -0,0 +1,924 @@
+//! Orthogonal edge routing.
+//!
+//! Every dependency becomes a polyline of horizontal and vertical segments
+//! that avoids topic nodes and unrelated group boxes:
+//!
+//! 1. Pick the facing sides of the two nodes.
+//! 2. Spread the edges that share a node side over separate ports.
+//! 3. Search a sparse grid built from obstacle boundaries between the two
+//!    ports (Dijkstra, with a penalty per bend).
+//!
+//! Cross-section edges that would pass other groups of the same row (or
+//! column) detour through a lane outside the groups instead: forward edges
+//! above the row (right of the column), backward edges below it (left of
+//! it). Parallel lanes are spaced `lane_gap` apart; see [`plan_lanes`].
+
+use super::tree::{EdgeRoute, GroupBox, LayoutConfig, LayoutMode, TopicPosition};
+use crate::models::roadmap::Dependency;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::Range;
+
+/// Extra cost of a 90° turn, in pixels of path length.
+const BEND_PENALTY: f64 = 30.0;
+/// How many times the search region grows to take in the obstacles it touches.
+const REGION_PASSES: usize = 2;
+
+/// Side of a node an edge attaches to.
+/// Discriminants double as direction indices (up, right, down, left).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+  Top = 0,
+  Right = 1,
+  Bottom = 2,
+  Left = 3,
+}
+
+/// Unit steps for the direction indices used by `Side`.
+const STEPS: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+impl Side {
+  /// Direction index pointing away from the node.
+  fn outward(self) -> usize {
+    self as usize
+  }
+
+  fn is_horizontal(self) -> bool {
+    matches!(self, Side::Left | Side::Right)
+  }
+}
+
+/// Axis-aligned rectangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rect {
+  left: f64,
+  top: f64,
+  right: f64,
+  bottom: f64,
+}
+
+impl Rect {
+  fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+    Self {
+      left: x,
+      top: y,
+      right: x + width,
+      bottom: y + height,
+    }
+  }
+
+  fn around(a: (f64, f64), b: (f64, f64)) -> Self {
+    Self {
+      left: a.0.min(b.0),
+      top: a.1.min(b.1),
+      right: a.0.max(b.0),
+      bottom: a.1.max(b.1),
+    }
+  }
+
+  fn inflate(self, d: f64) -> Self {
+    Self {
+      left: self.left - d,
+      top: self.top - d,
+      right: self.right + d,
+      bottom: self.bottom + d,
+    }
+  }
+
+  fn union(self, other: Self) -> Self {
+    Self {
+      left: self.left.min(other.left),
+      top: self.top.min(other.top),
+      right: self.right.max(other.right),
+      bottom: self.bottom.max(other.bottom),
+    }
+  }
+
+  fn clamp_to(self, bounds: Self) -> Self {
+    Self {
+      left: self.left.max(bounds.left),
+      top: self.top.max(bounds.top),
+      right: self.right.min(bounds.right),
+      bottom: self.bottom.min(bounds.bottom),
+    }
+  }
+
+  fn intersects(&self, other: &Self) -> bool {
+    self.left < other.right
+      && other.left < self.right
+      && self.top < other.bottom
+      && other.top < self.bottom
+  }
+}
+
+/// Area a route may not cross.
+#[derive(Debug, Clone, Copy)]
+struct Obstacle {
+  rect: Rect,
+  /// Closed obstacles also block their boundary. Used for the edge's own
+  /// endpoint nodes: the route may leave a port, but not slide along the node.
+  closed: bool,
+}
+
+impl Obstacle {
+  /// Indices of the sorted `values` that fall inside the obstacle's span
+  /// `lo..hi` (inclusive when closed).
+  fn covered(&self, values: &[f64], lo: f64, hi: f64) -> Range<usize> {
+    let start = values.partition_point(|&v| if self.closed { v < lo } else { v <= lo });
+    let end = values.partition_point(|&v| if self.closed { v <= hi } else { v < hi });
+    start..end.max(start)
+  }
+}
+
+/// Track outside the groups that a long-range edge detours through.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lane {
+  /// Side of the row or column of groups the lane runs along.
+  pub side: Side,
+  /// Parallel track, counted outwards from the groups.
+  pub track: usize,
+}
+
+/// Lanes for a set of dependencies and the canvas margin they need.
+#[derive(Debug, Clone, Default)]
+pub struct LanePlan {
+  /// One entry per dependency; `None` for edges routed directly.
+  pub lanes: Vec<Option<Lane>>,
+  /// Margin needed beyond `start_x`/`start_y`, indexed by `Side`.
+  pub margins: [f64; 4],
+}
+
+impl LanePlan {
+  /// Extra canvas margin needed on `side`.
+  pub fn margin(&self, side: Side) -> f64 {
+    self.margins[side as usize]
+  }
+}
+
+fn node_rect(pos: &TopicPosition) -> Rect {
+  Rect::new(pos.x, pos.y, pos.width, pos.height)
+}
+
+fn group_rect(group: &GroupBox) -> Rect {
+  Rect::new(group.x, group.y, group.width, group.height)
+}
+
+/// An edge waiting for its ports and route.
+struct PendingEdge<'a> {
+  dep: &'a Dependency,
+  from: &'a TopicPosition,
+  to: &'a TopicPosition,
+  from_group: &'a GroupBox,
+  to_group: &'a GroupBox,
+  lane: Option<Lane>,
+  from_side: Side,
+  to_side: Side,
+  from_port: (f64, f64),
+  to_port: (f64, f64),
+}
+
+/// Spans `(lo, hi)` already claimed on one lane track.
+type Track = Vec<(f64, f64)>;
+
+/// Choose a lane for every cross-section dependency that would otherwise
+/// pass other groups of its row or column.
+///
+/// Shorter edges get the tracks nearest the groups; edges whose spans
+/// overlap never share a track.
+pub fn plan_lanes(
+  groups: &[GroupBox],
+  positions: &[TopicPosition],
+  dependencies: &[Dependency],
+  config: &LayoutConfig,
+) -> LanePlan {
+  let by_id: HashMap<&str, &TopicPosition> = positions.iter().map(|p| (p.topic_id, p)).collect();
+  let by_section: HashMap<&str, &GroupBox> = groups.iter().map(|g| (g.section_id, g)).collect();
+
+  // (dependency, side, row/column coordinate, span along it)
+  let mut candidates: Vec<(usize, Side, f64, (f64, f64))> = Vec::new();
+  for (i, dep) in dependencies.iter().enumerate() {
+    let (Some(from), Some(to)) = (by_id.get(dep.from), by_id.get(dep.to)) else {
+      continue;
+    };
+    if from.section_id == to.section_id {
+      continue;
+    }
+    let (Some(from_group), Some(to_group)) = (
+      by_section.get(from.section_id),
+      by_section.get(to.section_id),
+    ) else {
+      continue;
+    };
+    if let Some(side) = detour_side(from_group, to_group, groups) {
+      let (key, a, b) = if side.is_horizontal() {
+        (from_group.x, from_group.y, to_group.y)
+      } else {
+        (from_group.y, from_group.x, to_group.x)
+      };
+      candidates.push((i, side, key, (a.min(b), a.max(b))));
+    }
+  }
+  candidates.sort_by(|a, b| (a.3.1 - a.3.0).total_cmp(&(b.3.1 - b.3.0)));
+
+  let mut plan = LanePlan {
+    lanes: vec![None; dependencies.len()],
+    margins: [0.0; 4],
+  };
+  // Tracks per side and row/column, each holding the spans claimed on it.
+  let mut tracks: HashMap<(Side, u64), Vec<Track>> = HashMap::new();
+  for (i, side, key, (lo, hi)) in candidates {
+    let claimed = tracks.entry((side, key.to_bits())).or_default();
+    let track = match claimed
+      .iter()
+      .position(|spans| spans.iter().all(|&(l, h)| h < lo || hi < l))
+    {
+      Some(track) => track,
+      None => {
+        claimed.push(Vec::new());
+        claimed.len() - 1
+      }
+    };
+    claimed[track].push((lo, hi));
+    plan.lanes[i] = Some(Lane { side, track });
+  }
+
+  for ((side, _), claimed) in &tracks {
+    let needed = config.edge_clearance + (claimed.len() as f64 + 1.0) * config.lane_gap;
+    let available = if side.is_horizontal() {
+      config.start_x
+    } else {
+      config.start_y
+    };
+    let margin = &mut plan.margins[*side as usize];
+    *margin = margin.max(needed - available);
+  }
+
+  plan
+}
+
+/// Side of the row (or column) a lane between the two groups runs along,
+/// or `None` when no other group of that row (column) lies between them.
+fn detour_side(from: &GroupBox, to: &GroupBox, groups: &[GroupBox]) -> Option<Side> {
+  let same = |a: f64, b: f64| (a - b).abs() < 1e-6;
+
+  if same(from.y, to.y) {
+    let (lo, hi) = (from.x.min(to.x), from.x.max(to.x));
+    let passes = groups
+      .iter()
+      .any(|g| same(g.y, from.y) && g.x > lo && g.x < hi);
+    passes.then_some(if to.x > from.x {
+      Side::Top
+    } else {
+      Side::Bottom
+    })
+  } else if same(from.x, to.x) {
+    let (lo, hi) = (from.y.min(to.y), from.y.max(to.y));
+    let passes = groups
+      .iter()
+      .any(|g| same(g.x, from.x) && g.y > lo && g.y < hi);
+    passes.then_some(if to.y > from.y {
+      Side::Right
+    } else {
+      Side::Left
+    })
+  } else {
+    None
+  }
+}
+
+/// Route every dependency whose endpoints both have a position.
+///
+/// `plan` comes from [`plan_lanes`] for the same groups and dependencies.
+/// `width`/`height` are the canvas size; routes never leave it.
+pub fn route_edges(
+  groups: &[GroupBox],
+  positions: &[TopicPosition],
+  dependencies: &[Dependency],
+  plan: &LanePlan,
+  config: &LayoutConfig,
+  width: f64,
+  height: f64,
+) -> Vec<EdgeRoute> {
+  let by_id: HashMap<&str, &TopicPosition> = positions.iter().map(|p| (p.topic_id, p)).collect();
+  let by_section: HashMap<&str, &GroupBox> = groups.iter().map(|g| (g.section_id, g)).collect();
+
+  // 1. Sides
+  let mut pending: Vec<PendingEdge> = dependencies
+    .iter()
+    .enumerate()
+    .filter_map(|(i, dep)| {
+      let from = *by_id.get(dep.from)?;
+      let to = *by_id.get(dep.to)?;
+      let from_group = *by_section.get(from.section_id)?;
+      let to_group = *by_section.get(to.section_id)?;
+      let lane = plan.lanes.get(i).copied().flatten();
+      let (from_side, to_side) = match lane {
+        Some(lane) => lane_sides(lane.side),
+        None => pick_sides(from, to, from_group, to_group, config),
+      };
+      Some(PendingEdge {
+        dep,
+        from,
+        to,
+        from_group,
+        to_group,
+        lane,
+        from_side,
+        to_side,
+        from_port: (0.0, 0.0),
+        to_port: (0.0, 0.0),
+      })
+    })
+    .collect();
+
+  // 2. Ports
+  assign_ports(&mut pending);
+
+  // 3. Routes
+  let bounds = Rect::new(0.0, 0.0, width, height);
+  let node_rects: Vec<(&str, Rect)> = positions
+    .iter()
+    .map(|p| (p.topic_id, node_rect(p)))
+    .collect();
+  let group_rects: Vec<(&str, Rect)> = groups
+    .iter()
+    .map(|g| (g.section_id, group_rect(g)))
+    .collect();
+
+  pending
+    .iter()
+    .map(|edge| {
+      let ends = [edge.dep.from, edge.dep.to];
+      let sections = [edge.from.section_id, edge.to.section_id];
+
+      let mut obstacles: Vec<Obstacle> = node_rects
+        .iter()
+        .map(|(id, rect)| {
+          if ends.contains(id) {
+            Obstacle {
+              rect: *rect,
+              closed: true,
+            }
+          } else {
+            Obstacle {
+              rect: rect.inflate(config.edge_clearance),
+              closed: false,
+            }
+          }
+        })
+        .collect();
+      obstacles.extend(
+        group_rects
+          .iter()
+          .filter(|(id, _)| !sections.contains(id))
+          .map(|(_, rect)| Obstacle {
+            rect: rect.inflate(config.edge_clearance),
+            closed: false,
+          }),
+      );
+
+      let points = edge
+        .lane
+        .and_then(|lane| detour(edge, lane, groups, &obstacles, bounds, config))
+        .or_else(|| {
+          route(
+            edge.from_port,
+            edge.from_side,
+            edge.to_port,
+            &obstacles,
+            bounds,
+            config,
+          )
+        })
+        .unwrap_or_else(|| elbow(edge.from_port, edge.from_side, edge.to_port));
+
+      EdgeRoute {
+        from: edge.dep.from,
+        to: edge.dep.to,
+        points,
+        is_cross_section: edge.from.section_id != edge.to.section_id,
+        kind: edge.dep.kind,
+        label: edge.dep.label,
+      }
+    })
+    .collect()
+}
+
+/// Choose which side each endpoint attaches on.
+///
+/// Within a section, side-by-side nodes connect horizontally and stacked
+/// nodes vertically (always vertically between layers in layered mode).
+/// Across sections, groups side by side connect right → left, and so does
+/// an edge from the end of one row to the start of a later row; stacked
+/// groups leave and enter on the right (down the gap beside them), except
+/// for the fishbone spine, which runs straight down.
+fn pick_sides(
+  from: &TopicPosition,
+  to: &TopicPosition,
+  from_group: &GroupBox,
+  to_group: &GroupBox,
+  config: &LayoutConfig,
+) -> (Side, Side) {
+  let vertical = if to.y >= from.y {
+    (Side::Bottom, Side::Top)
+  } else {
+    (Side::Top, Side::Bottom)
+  };
+
+  if from.section_id == to.section_id {
+    let between_layers = config.mode == LayoutMode::Layered
+      && (to.y >= from.y + from.height || from.y >= to.y + to.height);
+    return if between_layers {
+      vertical
+    } else if to.x >= from.x + from.width {
+      (Side::Right, Side::Left)
+    } else if to.x + to.width <= from.x {
+      (Side::Left, Side::Right)
+    } else {
+      vertical
+    };
+  }
+
+  let next_row = to_group.y >= from_group.y + from_group.height;
+  if to_group.x >= from_group.x + from_group.width {
+    (Side::Right, Side::Left)
+  } else if next_row && to_group.x + to_group.width <= from_group.x {
+    // Row wrap: leave the end of one row, enter the start of the next.
+    (Side::Right, Side::Left)
+  } else if to_group.x + to_group.width <= from_group.x {
+    (Side::Left, Side::Right)
+  } else if config.mode == LayoutMode::Fishbone {
+    vertical
+  } else {
+    (Side::Right, Side::Right)
+  }
+}
+
+/// Node sides for an edge detouring through a lane on `side`.
+fn lane_sides(side: Side) -> (Side, Side) {
+  match side {
+    Side::Top => (Side::Right, Side::Left),
+    Side::Bottom => (Side::Left, Side::Right),
+    Side::Right => (Side::Right, Side::Right),
+    Side::Left => (Side::Left, Side::Left),
+  }
+}
+
+/// Route `edge` out to its lane, along it, and back in to the target.
+/// `None` when the lane is blocked or either end can't reach it.
+fn detour(
+  edge: &PendingEdge,
+  lane: Lane,
+  groups: &[GroupBox],
+  obstacles: &[Obstacle],
+  bounds: Rect,
+  config: &LayoutConfig,
+) -> Option<Vec<(f64, f64)>> {
+  let (from, to) = (edge.from_group, edge.to_group);
+  let offset = config.edge_clearance + (lane.track as f64 + 1.0) * config.lane_gap;
+
+  // Groups of the row (column) between and including the two endpoints.
+  let spanned: Vec<&GroupBox> = if lane.side.is_horizontal() {
+    let (lo, hi) = (from.y.min(to.y), from.y.max(to.y));
+    groups
+      .iter()
+      .filter(|g| (g.x - from.x).abs() < 1e-6 && g.y >= lo && g.y <= hi)
+      .collect()
+  } else {
+    let (lo, hi) = (from.x.min(to.x), from.x.max(to.x));
+    groups
+      .iter()
+      .filter(|g| (g.y - from.y).abs() < 1e-6 && g.x >= lo && g.x <= hi)
+      .collect()
+  };
+
+  // Rows reach the lane through the gaps beside their end groups, one
+  // channel per track; columns step straight out from the ports.
+  let slots = ((config.group_gap_x - 2.0 * config.edge_clearance) / config.lane_gap)
+    .floor()
+    .max(1.0) as usize;
+  let channel = config.edge_clearance + ((lane.track % slots) as f64 + 1.0) * config.lane_gap;
+
+  let (entry, exit, inward) = match lane.side {
+    Side::Top => {
+      let y = spanned.iter().map(|g| g.y).fold(f64::INFINITY, f64::min) - offset;
+      (
+        (from.x + from.width + channel, y),
+        (to.x - channel, y),
+        Side::Bottom,
+      )
+    }
+    Side::Bottom => {
+      let y = spanned
+        .iter()
+        .map(|g| g.y + g.height)
+        .fold(f64::NEG_INFINITY, f64::max)
+        + offset;
+      (
+        (from.x - channel, y),
+        (to.x + to.width + channel, y),
+        Side::Top,
+      )
+    }
+    Side::Right => {
+      let x = spanned
+        .iter()
+        .map(|g| g.x + g.width)
+        .fold(f64::NEG_INFINITY, f64::max)
+        + offset;
+      ((x, edge.from_port.1), (x, edge.to_port.1), Side::Left)
+    }
+    Side::Left => {
+      let x = spanned.iter().map(|g| g.x).fold(f64::INFINITY, f64::min) - offset;
+      ((x, edge.from_port.1), (x, edge.to_port.1), Side::Right)
+    }
+  };
+
+  let run = Rect::around(entry, exit);
+  if obstacles.iter().any(|o| run.intersects(&o.rect)) {
+    return None;
+  }
+
+  let mut points = route(
+    edge.from_port,
+    edge.from_side,
+    entry,
+    obstacles,
+    bounds,
+    config,
+  )?;
+  points.extend(route(
+    exit,
+    inward,
+    edge.to_port,
+    obstacles,
+    bounds,
+    config,
+  )?);
+  Some(simplify(points))
+}
+
+/// One end of an edge asking for a port on a node side.
+struct PortRequest {
+  edge: usize,
+  is_source: bool,
+  /// Position of the other endpoint along the side's axis.
+  key: f64,
+}
+
+/// Spread edges sharing a node side evenly along it, ordered by where the
+/// other endpoint lies so neighbouring edges don't cross at the node.
+fn assign_ports(edges: &mut [PendingEdge]) {
+  let mut slots: HashMap<(&str, Side), Vec<PortRequest>> = HashMap::new();
+
+  for (i, edge) in edges.iter().enumerate() {
+    let key_for = |side: Side, other: &TopicPosition| {
+      if side.is_horizontal() {
+        other.y + other.height / 2.0
+      } else {
+        other.x + other.width / 2.0
+      }
+    };
+    slots
+      .entry((edge.dep.from, edge.from_side))
+      .or_default()
+      .push(PortRequest {
+        edge: i,
+        is_source: true,
+        key: key_for(edge.from_side, edge.to),
+      });
+    slots
+      .entry((edge.dep.to, edge.to_side))
+      .or_default()
+      .push(PortRequest {
+        edge: i,
+        is_source: false,
+        key: key_for(edge.to_side, edge.from),
+      });
+  }
+
+  for ((_, side), mut requests) in slots {
+    requests.sort_by(|a, b| a.key.total_cmp(&b.key));
+    let n = requests.len() as f64;
+    for (k, request) in requests.into_iter().enumerate() {
+      let t = (k as f64 + 1.0) / (n + 1.0);
+      let edge = &mut edges[request.edge];
+      if request.is_source {
+        edge.from_port = port_point(edge.from, side, t);
+      } else {
+        edge.to_port = port_point(edge.to, side, t);
+      }
+    }
+  }
+}
+
+/// Point at fraction `t` along `side` of a node.
+fn port_point(pos: &TopicPosition, side: Side, t: f64) -> (f64, f64) {
+  let h = pos.height;
+  match side {
+    Side::Top => (pos.x + pos.width * t, pos.y),
+    Side::Bottom => (pos.x + pos.width * t, pos.y + h),
+    Side::Left => (pos.x, pos.y + h * t),
+    Side::Right => (pos.x + pos.width, pos.y + h * t),
+  }
+}
+
+/// Three-segment fallback when no clear route exists.
+fn elbow(start: (f64, f64), side: Side, end: (f64, f64)) -> Vec<(f64, f64)> {
+  let points = if side.is_horizontal() {
+    let mid_x = (start.0 + end.0) / 2.0;
+    vec![start, (mid_x, start.1), (mid_x, end.1), end]
+  } else {
+    let mid_y = (start.1 + end.1) / 2.0;
+    vec![start, (start.0, mid_y), (end.0, mid_y), end]
+  };
+  simplify(points)
+}
+
+/// Search state ordered by lowest cost first.
+#[derive(PartialEq)]
+struct State {
+  cost: f64,
+  index: usize,
+}
+
+impl Eq for State {}
+
+impl Ord for State {
+  fn cmp(&self, other: &Self) -> Ordering {
+    other.cost.total_cmp(&self.cost)
+  }
+}
+
+impl PartialOrd for State {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// Cheapest orthogonal path from `start` to `end` that leaves `start` through
+/// `start_side` and never passes through an obstacle's interior.
+fn route(
+  start: (f64, f64),
+  start_side: Side,
+  end: (f64, f64),
+  obstacles: &[Obstacle],
+  bounds: Rect,
+  config: &LayoutConfig,
+) -> Option<Vec<(f64, f64)>> {
+  // Search region: both ports, grown over the obstacles in the way.
+  let margin = config.group_gap_x.max(config.group_gap_y);
+  let mut region = Rect::around(start, end).inflate(margin);
+  for _ in 0..REGION_PASSES {
+    for obstacle in obstacles {
+      if obstacle.rect.intersects(&region) {
+        region = region.union(obstacle.rect.inflate(margin));
+      }
+    }
+  }
+  let region = region.clamp_to(bounds).union(Rect::around(start, end));
+
+  let active: Vec<Obstacle> = obstacles
+    .iter()
+    .filter(|o| o.rect.intersects(&region))
+    .copied()
+    .collect();
+
+  // Grid lines: ports, region edges, obstacle edges and the channels between them.
+  let mut xs = vec![start.0, end.0, region.left, region.right];
+  let mut ys = vec![start.1, end.1, region.top, region.bottom];
+  for o in &active {
+    xs.extend([o.rect.left, o.rect.right]);
+    ys.extend([o.rect.top, o.rect.bottom]);
+  }
+  let xs = grid_lines(xs, region.left, region.right);
+  let ys = grid_lines(ys, region.top, region.bottom);
+
+  let locate = |lines: &[f64], v: f64| lines.iter().position(|l| (l - v).abs() < 1e-6);
+  let (sx, sy) = (locate(&xs, start.0)?, locate(&ys, start.1)?);
+  let (ex, ey) = (locate(&xs, end.0)?, locate(&ys, end.1)?);
+
+  let nx = xs.len();
+  let vertex = |xi: usize, yi: usize| yi * nx + xi;
+  let point = |v: usize| (xs[v % nx], ys[v / nx]);
+
+  // Mark the grid segments whose midpoint lies in an obstacle, indexed by
+  // their left (horizontal) or upper (vertical) vertex.
+  let mid = |lines: &[f64]| -> Vec<f64> { lines.windows(2).map(|w| (w[0] + w[1]) / 2.0).collect() };
+  let (x_mids, y_mids) = (mid(&xs), mid(&ys));
+  let mut blocked_right = vec![false; nx * ys.len()];
+  let mut blocked_down = vec![false; nx * ys.len()];
+  for o in &active {
+    let r = o.rect;
+    for yi in o.covered(&ys, r.top, r.bottom) {
+      for xi in o.covered(&x_mids, r.left, r.right) {
+        blocked_right[vertex(xi, yi)] = true;
+      }
+    }
+    for yi in o.covered(&y_mids, r.top, r.bottom) {
+      for xi in o.covered(&xs, r.left, r.right) {
+        blocked_down[vertex(xi, yi)] = true;
+      }
+    }
+  }
+  let blocked = |v: usize, w: usize, dir: usize| match dir {
+    0 => blocked_down[w],
+    1 => blocked_right[v],
+    2 => blocked_down[v],
+    _ => blocked_right[w],
+  };
+
+  let total = nx * ys.len() * 4;
+  let mut dist = vec![f64::INFINITY; total];
+  let mut prev = vec![usize::MAX; total];
+  let mut heap = BinaryHeap::new();
+
+  let first = vertex(sx, sy) * 4 + start_side.outward();
+  dist[first] = 0.0;
+  heap.push(State {
+    cost: 0.0,
+    index: first,
+  });
+
+  let goal = vertex(ex, ey);
+  let mut reached = None;
+
+  while let Some(State { cost, index }) = heap.pop() {
+    if cost > dist[index] {
+      continue;
+    }
+    let (v, dir) = (index / 4, index % 4);
+    if v == goal {
+      reached = Some(index);
+      break;
+    }
+
+    let (xi, yi) = ((v % nx) as i64, (v / nx) as i64);
+    for (next_dir, (dx, dy)) in STEPS.iter().enumerate() {
+      let (nxi, nyi) = (xi + dx, yi + dy);
+      if nxi < 0 || nyi < 0 || nxi >= nx as i64 || nyi >= ys.len() as i64 {
+        continue;
+      }
+      let w = vertex(nxi as usize, nyi as usize);
+      if blocked(v, w, next_dir) {
+        continue;
+      }
+      let (a, b) = (point(v), point(w));
+
+      let length = (a.0 - b.0).abs() + (a.1 - b.1).abs();
+      let bend = if next_dir == dir { 0.0 } else { BEND_PENALTY };
+      let next = w * 4 + next_dir;
+      let next_cost = cost + length + bend;
+      if next_cost < dist[next] {
+        dist[next] = next_cost;
+        prev[next] = index;
+        heap.push(State {
+          cost: next_cost,
+          index: next,
+        });
+      }
+    }
+  }
+
+  let mut index = reached?;
+  let mut points = vec![point(index / 4)];
+  while prev[index] != usize::MAX {
+    index = prev[index];
+    points.push(point(index / 4));
+  }
+  points.reverse();
+
+  Some(simplify(points))
+}
+
+/// Sorted, de-duplicated coordinates within `[lo, hi]`, plus the midpoints
+/// between neighbours so routes can run down the middle of a channel.
+fn grid_lines(mut values: Vec<f64>, lo: f64, hi: f64) -> Vec<f64> {
+  values.retain(|v| *v >= lo && *v <= hi);
+  values.sort_by(f64::total_cmp);
+  values.dedup_by(|a, b| (*a - *b).abs() < 1e-6);
+
+  let mut lines = Vec::with_capacity(values.len() * 2);
+  for pair in values.windows(2) {
+    lines.push(pair[0]);
+    lines.push((pair[0] + pair[1]) / 2.0);
+  }
+  lines.extend(values.last());
+  lines
+}
+
+/// Drop repeated points and the middle of collinear runs.
+fn simplify(points: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+  let mut out: Vec<(f64, f64)> = Vec::with_capacity(points.len());
+  for p in points {
+    if out
+      .last()
+      .is_some_and(|q| (q.0 - p.0).abs() < 1e-6 && (q.1 - p.1).abs() < 1e-6)
+    {
+      continue;
+    }
+    if out.len() >= 2 {
+      let a = out[out.len() - 2];
+      let b = out[out.len() - 1];
+      let collinear = ((a.0 - b.0).abs() < 1e-6 && (b.0 - p.0).abs() < 1e-6)
+        || ((a.1 - b.1).abs() < 1e-6 && (b.1 - p.1).abs() < 1e-6);
+      if collinear {
+        out.pop();
+      }
+    }
+    out.push(p);
+  }
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn bounds() -> Rect {
+    Rect::new(0.0, 0.0, 400.0, 300.0)
+  }
+
+  /// Whether the axis-aligned segment `a`–`b` passes through the interior
+  /// of `rect`.
+  fn crosses(a: (f64, f64), b: (f64, f64), rect: Rect) -> bool {
+    Rect::around(a, b).intersects(&rect)
+  }
+
+  #[test]
+  fn route_runs_straight_when_clear() {
+    let points = route(
+      (50.0, 100.0),
+      Side::Right,
+      (250.0, 100.0),
+      &[],
+      bounds(),
+      &LayoutConfig::default(),
+    );
+    assert_eq!(points, Some(vec![(50.0, 100.0), (250.0, 100.0)]));
+  }
+
+  #[test]
+  fn route_avoids_a_blocking_rect() {
+    let wall = Rect::new(120.0, 40.0, 60.0, 120.0);
+    let obstacles = [Obstacle {
+      rect: wall,
+      closed: false,
+    }];
+    let points = route(
+      (50.0, 100.0),
+      Side::Right,
+      (250.0, 100.0),
+      &obstacles,
+      bounds(),
+      &LayoutConfig::default(),
+    )
+    .expect("a way around the wall");
+
+    assert_eq!(points.first(), Some(&(50.0, 100.0)));
+    assert_eq!(points.last(), Some(&(250.0, 100.0)));
+    for pair in points.windows(2) {
+      let (a, b) = (pair[0], pair[1]);
+      assert!(a.0 == b.0 || a.1 == b.1, "{a:?} -> {b:?} is not orthogonal");
+      assert!(!crosses(a, b, wall), "{a:?} -> {b:?} crosses the wall");
+    }
+  }
+
+  #[test]
+  fn route_fails_when_the_end_is_walled_in() {
+    let obstacles = [Obstacle {
+      rect: Rect::new(200.0, 50.0, 100.0, 100.0),
+      closed: true,
+    }];
+    let points = route(
+      (50.0, 100.0),
+      Side::Right,
+      (250.0, 100.0),
+      &obstacles,
+      bounds(),
+      &LayoutConfig::default(),
+    );
+    assert_eq!(points, None);
+  }
+
+  #[test]
+  fn simplify_drops_repeats_and_collinear_points() {
+    let points = vec![
+      (0.0, 0.0),
+      (0.0, 0.0),
+      (10.0, 0.0),
+      (20.0, 0.0),
+      (20.0, 30.0),
+    ];
+    assert_eq!(
+      simplify(points),
+      vec![(0.0, 0.0), (20.0, 0.0), (20.0, 30.0)]
+    );
+  }
+
+  #[test]
+  fn grid_lines_add_channel_midpoints() {
+    let lines = grid_lines(vec![30.0, 10.0, 10.0, 90.0], 0.0, 50.0);
+    assert_eq!(lines, vec![10.0, 20.0, 30.0]);
+  }
+}
@@ -0,0 +1,229 @@
+//! Uniform-grid spatial index over a `LayoutResult`.
+//!
+//! The diagram only mounts the nodes and edges that intersect the visible
+//! part of the canvas. The index buckets each topic rect and each edge
+//! segment into fixed-size cells, so a viewport query touches only the cells
+//! it overlaps instead of scanning every item.
+
+use super::tree::LayoutResult;
+
+/// Side of one grid cell, in diagram units. Roughly a node's footprint
+/// scaled up, so a typical viewport covers a few dozen cells.
+pub const CELL_SIZE: f64 = 400.0;
+
+/// Axis-aligned bounds: `(x, y, width, height)`.
+pub type Bounds = (f64, f64, f64, f64);
+
+/// Indices (into `LayoutResult::topics` / `edges`) of the items in a region,
+/// in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Visible {
+  pub topics: Vec<usize>,
+  pub edges: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpatialIndex {
+  origin: (f64, f64),
+  cols: usize,
+  rows: usize,
+  /// Per cell (row-major), the topics whose rect overlaps it.
+  topic_cells: Vec<Vec<usize>>,
+  /// Per cell (row-major), the edges with a segment overlapping it.
+  edge_cells: Vec<Vec<usize>>,
+  topic_bounds: Vec<Bounds>,
+  /// Per edge, the bounds of each segment.
+  edge_segments: Vec<Vec<Bounds>>,
+}
+
+fn intersects(a: Bounds, b: Bounds) -> bool {
+  a.0 <= b.0 + b.2 && b.0 <= a.0 + a.2 && a.1 <= b.1 + b.3 && b.1 <= a.1 + a.3
+}
+
+fn segment_bounds(a: (f64, f64), b: (f64, f64)) -> Bounds {
+  let (x, y) = (a.0.min(b.0), a.1.min(b.1));
+  (x, y, (a.0 - b.0).abs(), (a.1 - b.1).abs())
+}
+
+impl SpatialIndex {
+  /// Index every topic and edge of `layout`.
+  pub fn new(layout: &LayoutResult) -> Self {
+    let origin = (layout.min_x, 0.0);
+    let cols = (layout.total_width / CELL_SIZE).ceil().max(1.0) as usize;
+    let rows = (layout.total_height / CELL_SIZE).ceil().max(1.0) as usize;
+
+    let topic_bounds: Vec<Bounds> = layout
+      .topics
+      .iter()
+      .map(|p| (p.x, p.y, p.width, p.height))
+      .collect();
+    let edge_segments: Vec<Vec<Bounds>> = layout
+      .edges
+      .iter()
+      .map(|e| {
+        e.points
+          .windows(2)
+          .map(|w| segment_bounds(w[0], w[1]))
+          .collect()
+      })
+      .collect();
+
+    let mut index = Self {
+      origin,
+      cols,
+      rows,
+      topic_cells: vec![Vec::new(); cols * rows],
+      edge_cells: vec![Vec::new(); cols * rows],
+      topic_bounds,
+      edge_segments,
+    };
+
+    for i in 0..index.topic_bounds.len() {
+      for cell in index.cells(index.topic_bounds[i]) {
+        index.topic_cells[cell].push(i);
+      }
+    }
+    for i in 0..index.edge_segments.len() {
+      for s in 0..index.edge_segments[i].len() {
+        for cell in index.cells(index.edge_segments[i][s]) {
+          let bucket = &mut index.edge_cells[cell];
+          if bucket.last() != Some(&i) {
+            bucket.push(i);
+          }
+        }
+      }
+    }
+    index
+  }
+
+  /// Row-major indices of the cells overlapping `b`, clamped to the grid.
+  fn cells(&self, b: Bounds) -> Vec<usize> {
+    let col =
+      |x: f64| (((x - self.origin.0) / CELL_SIZE).floor().max(0.0) as usize).min(self.cols - 1);
+    let row =
+      |y: f64| (((y - self.origin.1) / CELL_SIZE).floor().max(0.0) as usize).min(self.rows - 1);
+    let (c0, c1) = (col(b.0), col(b.0 + b.2));
+    let (r0, r1) = (row(b.1), row(b.1 + b.3));
+    (r0..=r1)
+      .flat_map(|r| (c0..=c1).map(move |c| r * self.cols + c))
+      .collect()
+  }
+
+  /// Topics and edges intersecting `region`.
+  pub fn query(&self, region: Bounds) -> Visible {
+    let cells = self.cells(region);
+
+    let mut topics: Vec<usize> = cells
+      .iter()
+      .flat_map(|&c| self.topic_cells[c].iter().copied())
+      .filter(|&i| intersects(self.topic_bounds[i], region))
+      .collect();
+    topics.sort_unstable();
+    topics.dedup();
+
+    let mut edges: Vec<usize> = cells
+      .iter()
+      .flat_map(|&c| self.edge_cells[c].iter().copied())
+      .collect();
+    edges.sort_unstable();
+    edges.dedup();
+    edges.retain(|&i| self.edge_segments[i].iter().any(|&s| intersects(s, region)));
+
+    Visible { topics, edges }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::layout::tree::{EdgeRoute, TopicPosition};
+  use crate::models::roadmap::DependencyKind;
+
+  fn node(topic_id: &'static str, x: f64, y: f64) -> TopicPosition {
+    TopicPosition {
+      topic_id,
+      section_id: "sec",
+      x,
+      y,
+      width: 200.0,
+      height: 44.0,
+    }
+  }
+
+  fn edge(points: Vec<(f64, f64)>) -> EdgeRoute {
+    EdgeRoute {
+      from: "a",
+      to: "b",
+      points,
+      is_cross_section: false,
+      kind: DependencyKind::Prerequisite,
+      label: None,
+    }
+  }
+
+  /// Three nodes far apart, and an L-shaped edge from the first to the
+  /// third running along the top and down the right.
+  fn layout() -> LayoutResult {
+    LayoutResult {
+      groups: Vec::new(),
+      topics: vec![
+        node("a", 0.0, 0.0),
+        node("b", 1000.0, 1000.0),
+        node("c", 1800.0, 0.0),
+      ],
+      edges: vec![edge(vec![(200.0, 22.0), (1500.0, 22.0), (1500.0, 1600.0)])],
+      min_x: 0.0,
+      total_width: 2000.0,
+      total_height: 2000.0,
+    }
+  }
+
+  #[test]
+  fn query_returns_only_overlapping_items() {
+    let index = SpatialIndex::new(&layout());
+    assert_eq!(
+      index.query((950.0, 950.0, 100.0, 100.0)),
+      Visible {
+        topics: vec![1],
+        edges: vec![],
+      }
+    );
+  }
+
+  #[test]
+  fn query_finds_edges_by_their_segments() {
+    let index = SpatialIndex::new(&layout());
+    // Inside the edge's bounding box, but away from both of its segments.
+    assert_eq!(
+      index.query((600.0, 600.0, 100.0, 100.0)),
+      Visible::default()
+    );
+    // Across the vertical segment only.
+    assert_eq!(
+      index.query((1400.0, 800.0, 200.0, 100.0)),
+      Visible {
+        topics: vec![],
+        edges: vec![0],
+      }
+    );
+  }
+
+  #[test]
+  fn query_of_the_whole_canvas_returns_everything() {
+    let index = SpatialIndex::new(&layout());
+    assert_eq!(
+      index.query((0.0, 0.0, 2000.0, 2000.0)),
+      Visible {
+        topics: vec![0, 1, 2],
+        edges: vec![0],
+      }
+    );
+  }
+
+  #[test]
+  fn regions_outside_the_canvas_are_clamped() {
+    let index = SpatialIndex::new(&layout());
+    let visible = index.query((-500.0, -500.0, 600.0, 600.0));
+    assert_eq!(visible.topics, vec![0]);
+  }
+}
@@ -0,0 +1,100 @@
+//! Title wrapping for topic nodes.
+//!
+//! SVG text doesn't wrap, so titles are split into lines up front using an
+//! approximate per-character advance (`LayoutConfig::char_width`). Titles
+//! use a monospace font, which keeps the estimate close.
+
+use super::tree::LayoutConfig;
+use crate::models::roadmap::Topic;
+
+/// Split `title` into lines of at most `max_chars` characters.
+///
+/// Breaks at spaces; a single word longer than a line is split mid-word.
+pub fn wrap_title(title: &str, max_chars: usize) -> Vec<String> {
+  let max_chars = max_chars.max(1);
+  let mut lines: Vec<String> = Vec::new();
+  let mut line = String::new();
+
+  for word in title.split_whitespace() {
+    let mut word: Vec<char> = word.chars().collect();
+    let line_len = line.chars().count();
+
+    if line_len > 0 && line_len + 1 + word.len() <= max_chars {
+      line.push(' ');
+      line.extend(word);
+      continue;
+    }
+    if line_len > 0 {
+      lines.push(std::mem::take(&mut line));
+    }
+    while word.len() > max_chars {
+      let rest = word.split_off(max_chars);
+      lines.push(word.into_iter().collect());
+      word = rest;
+    }
+    line.extend(word);
+  }
+
+  if !line.is_empty() || lines.is_empty() {
+    lines.push(line);
+  }
+  lines
+}
+
+/// Title lines for a node of `config.node_width`.
+pub fn title_lines(title: &str, config: &LayoutConfig) -> Vec<String> {
+  let usable = config.node_width - 2.0 * config.text_padding_x;
+  wrap_title(title, (usable / config.char_width).floor() as usize)
+}
+
+/// Height of a node whose title wraps onto `lines` lines.
+/// One line keeps the base `node_height`; each extra line adds `line_height`.
+pub fn node_height_for(lines: usize, config: &LayoutConfig) -> f64 {
+  config.node_height + lines.saturating_sub(1) as f64 * config.line_height
+}
+
+/// Height of the node for `topic`.
+pub fn topic_height(topic: &Topic, config: &LayoutConfig) -> f64 {
+  node_height_for(title_lines(topic.title, config).len(), config)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn short_titles_stay_on_one_line() {
+    assert_eq!(wrap_title("Ownership", 20), vec!["Ownership"]);
+  }
+
+  #[test]
+  fn titles_break_at_spaces() {
+    assert_eq!(
+      wrap_title("Error Handling and Safety", 14),
+      vec!["Error Handling", "and Safety"]
+    );
+  }
+
+  #[test]
+  fn a_long_word_is_split() {
+    assert_eq!(
+      wrap_title("Metaprogramming basics", 6),
+      vec!["Metapr", "ogramm", "ing", "basics"]
+    );
+  }
+
+  #[test]
+  fn an_empty_title_is_one_empty_line() {
+    assert_eq!(wrap_title("", 10), vec![""]);
+  }
+
+  #[test]
+  fn each_extra_line_adds_line_height() {
+    let config = LayoutConfig::default();
+    assert_eq!(node_height_for(1, &config), config.node_height);
+    assert_eq!(
+      node_height_for(3, &config),
+      config.node_height + 2.0 * config.line_height
+    );
+  }
+}
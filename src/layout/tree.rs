@@ -1,15 +1,97 @@
-//! Horizontal group layout engine.
-//! Sections flow left-to-right as labeled group boxes.
-//! Topics within each group flow top-to-bottom.
+//! Group layout engine.
+//!
+//! - `LayoutMode::HorizontalGroups`: sections flow as labeled group boxes.
+//!   Topics within each group flow top-to-bottom, or fill a grid for
+//!   sections declared with `SectionLayout::Grid`.
+//! - `LayoutMode::Fishbone`: sections stack top-to-bottom. `Center` topics
+//!   form a vertical spine and `Left`/`Right` topics branch off either side.
+//! - `LayoutMode::Layered`: sections flow as group boxes; topics are layered
+//!   by their longest prerequisite chain (see [`super::layered`]).
+//!
+//! Group boxes flow left-to-right or top-to-bottom per `LayoutOrientation`.
+//! Edges are then routed around nodes and groups (see [`super::routing`]).
 
-use crate::models::roadmap::{Dependency, Section, Topic, TopicType};
+use super::routing::{self, Side};
+use super::{layered, text};
+use crate::models::roadmap::{
+  Dependency, DependencyKind, Placement, Section, SectionLayout, Topic, TopicType,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Overall arrangement of sections and their topics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutMode {
+  /// Sections side by side; topics listed (or gridded) inside each group.
+  #[default]
+  HorizontalGroups,
+  /// Sections stacked along a vertical spine; topics placed by `Placement`.
+  Fishbone,
+  /// Sections side by side; topics layered in learning order by dependencies.
+  Layered,
+}
+
+impl LayoutMode {
+  pub const ALL: [LayoutMode; 3] = [
+    LayoutMode::HorizontalGroups,
+    LayoutMode::Fishbone,
+    LayoutMode::Layered,
+  ];
+
+  /// Stable name, as stored in `local_storage`.
+  pub fn as_str(self) -> &'static str {
+    match self {
+      LayoutMode::HorizontalGroups => "groups",
+      LayoutMode::Fishbone => "fishbone",
+      LayoutMode::Layered => "layered",
+    }
+  }
+
+  /// The mode named `name` by `as_str`.
+  pub fn from_name(name: &str) -> Option<Self> {
+    LayoutMode::ALL.into_iter().find(|m| m.as_str() == name)
+  }
+
+  /// Shown in the header's layout switcher.
+  pub fn label(self) -> &'static str {
+    match self {
+      LayoutMode::HorizontalGroups => "Groups",
+      LayoutMode::Fishbone => "Fishbone",
+      LayoutMode::Layered => "Learning order",
+    }
+  }
+}
+
+/// Direction in which section groups follow each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutOrientation {
+  /// Groups left to right, wrapping into a new row at `max_width`.
+  #[default]
+  Horizontal,
+  /// Rows of `groups_per_row` groups, stacked top to bottom.
+  Vertical,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct LayoutConfig {
+  /// Arrangement strategy.
+  pub mode: LayoutMode,
+  /// Group flow direction. Ignored in fishbone mode, whose spine is always vertical.
+  pub orientation: LayoutOrientation,
+  /// Number of groups side by side in each row (vertical orientation).
+  pub groups_per_row: usize,
+  /// Widest a row of groups may get before the next group starts a new row.
+  /// `None` never wraps. Fishbone mode always has one group per row.
+  pub max_width: Option<f64>,
   /// Width of each topic node.
   pub node_width: f64,
-  /// Height of each topic node.
+  /// Height of a single-line topic node; each wrapped title line adds `line_height`.
   pub node_height: f64,
+  /// Horizontal inset of the title text inside a node.
+  pub text_padding_x: f64,
+  /// Approximate advance of one title character, used to wrap titles.
+  pub char_width: f64,
+  /// Distance between wrapped title lines.
+  pub line_height: f64,
   /// Height of the section group header bar.
   pub header_height: f64,
   /// Horizontal padding inside a group box.
@@ -18,8 +100,20 @@ pub struct LayoutConfig {
   pub group_padding_y: f64,
   /// Vertical gap between topics within a group.
   pub topic_gap_y: f64,
+  /// Horizontal gap between grid columns within a group.
+  pub topic_gap_x: f64,
+  /// Vertical gap between dependency layers (layered mode).
+  pub layer_gap_y: f64,
+  /// Horizontal gap between the spine and branch columns (fishbone mode).
+  pub branch_gap_x: f64,
   /// Horizontal gap between consecutive group boxes.
   pub group_gap_x: f64,
+  /// Vertical gap between rows of group boxes.
+  pub group_gap_y: f64,
+  /// Minimum distance an edge keeps from nodes and groups it passes.
+  pub edge_clearance: f64,
+  /// Spacing between parallel lanes of long-range cross-section edges.
+  pub lane_gap: f64,
   /// Starting X offset for the first group.
   pub start_x: f64,
   /// Starting Y offset for all groups.
@@ -29,13 +123,26 @@ pub struct LayoutConfig {
 impl Default for LayoutConfig {
   fn default() -> Self {
     Self {
+      mode: LayoutMode::HorizontalGroups,
+      orientation: LayoutOrientation::Horizontal,
+      groups_per_row: 1,
+      max_width: Some(1600.0),
       node_width: 200.0,
       node_height: 44.0,
+      text_padding_x: 12.0,
+      char_width: 9.2,
+      line_height: 18.0,
       header_height: 36.0,
       group_padding_x: 14.0,
       group_padding_y: 12.0,
       topic_gap_y: 8.0,
+      topic_gap_x: 10.0,
+      layer_gap_y: 28.0,
+      branch_gap_x: 48.0,
       group_gap_x: 52.0,
+      group_gap_y: 40.0,
+      edge_clearance: 4.0,
+      lane_gap: 8.0,
       start_x: 40.0,
       start_y: 40.0,
     }
@@ -52,6 +159,8 @@ pub struct GroupBox {
   pub height: f64,
   /// Display label (the section title).
   pub label: &'static str,
+  /// Collapsed down to its header bar; its topics get no position.
+  pub collapsed: bool,
 }
 
 /// Computed screen position for a single topic node.
@@ -62,6 +171,20 @@ pub struct TopicPosition {
   pub x: f64,
   pub y: f64,
   pub width: f64,
+  /// Grows with the number of wrapped title lines.
+  pub height: f64,
+}
+
+/// A routed connector between two topics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeRoute {
+  pub from: &'static str,
+  pub to: &'static str,
+  /// Orthogonal polyline from the source port to the target port.
+  pub points: Vec<(f64, f64)>,
+  pub is_cross_section: bool,
+  pub kind: DependencyKind,
+  pub label: Option<&'static str>,
 }
 
 /// Full result of the layout pass.
@@ -69,30 +192,40 @@ pub struct TopicPosition {
 pub struct LayoutResult {
   pub groups: Vec<GroupBox>,
   pub topics: Vec<TopicPosition>,
+  pub edges: Vec<EdgeRoute>,
   pub min_x: f64,
   pub total_width: f64,
   pub total_height: f64,
 }
 
-/// Compute the horizontal group layout.
+/// Compute the group layout for `config.mode` and `config.orientation`.
 ///
-/// Sections are sorted by `order` and laid out left to right.
+/// Sections are sorted by `order` and laid out left to right, or top to
+/// bottom in rows of `groups_per_row` (`Vertical`, and always in `Fishbone`).
+/// Rows also wrap before a group that would make them wider than `max_width`.
 /// Topics within each section are sorted: `Main` types first, then `Sub`.
 /// Within a type, they're ordered by `row` hint then array position.
+/// In horizontal-groups mode, `List` sections stack their topics in one
+/// column and `Grid` sections put `Main` topics on top and spread `Sub`
+/// topics over `cols` columns. Fishbone and layered modes ignore
+/// `Section.layout`; layered mode ranks topics by the section's internal
+/// `dependencies`.
+///
+/// Sections whose id is in `collapsed` shrink to their header bar and their
+/// topics are left out. Edges to those topics attach to the header bar
+/// instead; their `EdgeRoute` endpoint is the section id.
 pub fn compute_layout(
   sections: &[Section],
   topics: &[Topic],
-  _dependencies: &[Dependency],
+  dependencies: &[Dependency],
+  collapsed: &HashSet<String>,
   config: &LayoutConfig,
 ) -> LayoutResult {
   let mut sorted_sections = sections.to_vec();
   sorted_sections.sort_by_key(|s| s.order);
 
-  let mut groups: Vec<GroupBox> = Vec::new();
-  let mut topic_positions: Vec<TopicPosition> = Vec::new();
-
-  let mut current_x = config.start_x;
-  let mut max_group_height: f64 = 0.0;
+  // Pass 1: arrange each section's topics and size its group box.
+  let mut blocks: Vec<(&Section, SectionBlock)> = Vec::new();
 
   for section in sorted_sections.iter() {
     // Collect topics for this section
@@ -121,17 +254,50 @@ pub fn compute_layout(
       ra.cmp(&rb)
     });
 
-    let n = section_topics.len();
-    let group_inner_width = config.node_width;
-    let group_width = group_inner_width + 2.0 * config.group_padding_x;
-    let topics_height =
-      n as f64 * config.node_height + n.saturating_sub(1) as f64 * config.topic_gap_y;
-    let group_height =
-      config.header_height + config.group_padding_y + topics_height + config.group_padding_y;
+    let block = match (config.mode, &section.layout) {
+      (LayoutMode::Fishbone, _) => fishbone_block(&section_topics, config),
+      (LayoutMode::Layered, _) => layered_block(&section_topics, dependencies, config),
+      (LayoutMode::HorizontalGroups, SectionLayout::List) => list_block(&section_topics, config),
+      (LayoutMode::HorizontalGroups, SectionLayout::Grid { cols }) => {
+        grid_block(&section_topics, *cols, config)
+      }
+    };
 
-    let group_x = current_x;
-    let group_y = config.start_y;
+    blocks.push((section, block));
+  }
 
+  // Pass 2: flow the group boxes into rows.
+  let per_row = match (config.mode, config.orientation) {
+    (LayoutMode::Fishbone, _) => 1,
+    (_, LayoutOrientation::Horizontal) => usize::MAX,
+    (_, LayoutOrientation::Vertical) => config.groups_per_row.max(1),
+  };
+  let sizes: Vec<(f64, f64)> = blocks
+    .iter()
+    .map(|(section, block)| {
+      if collapsed.contains(section.id) {
+        (
+          block.width + 2.0 * config.group_padding_x,
+          config.header_height,
+        )
+      } else {
+        group_size(block, config)
+      }
+    })
+    .collect();
+  let origins = flow_rows(&sizes, per_row, config);
+
+  let mut groups: Vec<GroupBox> = Vec::with_capacity(blocks.len());
+  let mut topic_positions: Vec<TopicPosition> = Vec::new();
+  // Collapsed groups stand in for their topics when routing edges.
+  let mut stand_ins: Vec<TopicPosition> = Vec::new();
+  let mut max_right: f64 = 0.0;
+  let mut max_bottom: f64 = 0.0;
+
+  for (((section, block), (group_width, group_height)), (group_x, group_y)) in
+    blocks.into_iter().zip(sizes).zip(origins)
+  {
+    let is_collapsed = collapsed.contains(section.id);
     groups.push(GroupBox {
       section_id: section.id,
       x: group_x,
@@ -139,72 +305,572 @@ pub fn compute_layout(
       width: group_width,
       height: group_height,
       label: section.title,
+      collapsed: is_collapsed,
     });
 
-    // Position each topic within the group
-    for (i, topic) in section_topics.iter().enumerate() {
-      let x = group_x + config.group_padding_x;
-      let y = group_y
-        + config.header_height
-        + config.group_padding_y
-        + i as f64 * (config.node_height + config.topic_gap_y);
+    max_right = max_right.max(group_x + group_width);
+    max_bottom = max_bottom.max(group_y + group_height);
 
+    if is_collapsed {
+      stand_ins.push(TopicPosition {
+        topic_id: section.id,
+        section_id: section.id,
+        x: group_x,
+        y: group_y,
+        width: group_width,
+        height: group_height,
+      });
+      continue;
+    }
+
+    // Position each topic within the group
+    let origin_x = group_x + config.group_padding_x;
+    let origin_y = group_y + config.header_height + config.group_padding_y;
+    for (topic, dx, dy) in block.slots {
       topic_positions.push(TopicPosition {
         topic_id: topic.id,
         section_id: section.id,
-        x,
-        y,
+        x: origin_x + dx,
+        y: origin_y + dy,
         width: config.node_width,
+        height: text::topic_height(topic, config),
       });
     }
+  }
 
-    max_group_height = max_group_height.max(group_height);
-    current_x += group_width + config.group_gap_x;
+  // Re-point edges at the stand-ins of collapsed sections, dropping the
+  // ones that end up inside a single collapsed section or repeated.
+  let hidden: HashMap<&str, &str> = topics
+    .iter()
+    .filter(|t| collapsed.contains(t.section_id))
+    .map(|t| (t.id, t.section_id))
+    .collect();
+  let mut seen = HashSet::new();
+  let dependencies: Vec<Dependency> = dependencies
+    .iter()
+    .map(|dep| Dependency {
+      from: hidden.get(dep.from).copied().unwrap_or(dep.from),
+      to: hidden.get(dep.to).copied().unwrap_or(dep.to),
+      ..dep.clone()
+    })
+    .filter(|dep| dep.from != dep.to && seen.insert((dep.from, dep.to)))
+    .collect();
+
+  // Widen the margins where long-range edges need room for their lanes.
+  let mut endpoints: Vec<TopicPosition> =
+    topic_positions.iter().cloned().chain(stand_ins).collect();
+  let lanes = routing::plan_lanes(&groups, &endpoints, &dependencies, config);
+  let shift_x = lanes.margin(Side::Left);
+  let shift_y = lanes.margin(Side::Top);
+  for group in &mut groups {
+    group.x += shift_x;
+    group.y += shift_y;
+  }
+  for pos in topic_positions.iter_mut().chain(&mut endpoints) {
+    pos.x += shift_x;
+    pos.y += shift_y;
   }
 
   // Total canvas dimensions (add right/bottom margin)
-  let total_width = (current_x - config.group_gap_x + config.start_x).max(0.0);
-  let total_height = config.start_y + max_group_height + config.start_y;
+  let total_width = max_right + shift_x + config.start_x + lanes.margin(Side::Right);
+  let total_height = max_bottom + shift_y + config.start_y + lanes.margin(Side::Bottom);
+
+  let edges = routing::route_edges(
+    &groups,
+    &endpoints,
+    &dependencies,
+    &lanes,
+    config,
+    total_width,
+    total_height,
+  );
 
   LayoutResult {
     groups,
     topics: topic_positions,
+    edges,
     min_x: 0.0,
     total_width,
     total_height,
   }
 }
 
+/// Outer `(width, height)` of a group box around `block`.
+fn group_size(block: &SectionBlock, config: &LayoutConfig) -> (f64, f64) {
+  (
+    block.width + 2.0 * config.group_padding_x,
+    config.header_height + config.group_padding_y + block.height + config.group_padding_y,
+  )
+}
+
+/// Top-left corners for groups of the given sizes, at most `per_row` to a
+/// row and no row wider than `config.max_width` (a wider group still gets a
+/// row of its own). Each row is as tall as its tallest group.
+fn flow_rows(sizes: &[(f64, f64)], per_row: usize, config: &LayoutConfig) -> Vec<(f64, f64)> {
+  let right_limit = config.max_width.map(|w| config.start_x + w);
+  let mut origins = Vec::with_capacity(sizes.len());
+  let mut x = config.start_x;
+  let mut row_top = config.start_y;
+  let mut row_height: f64 = 0.0;
+  let mut in_row = 0;
+
+  for &(width, height) in sizes {
+    let too_wide = right_limit.is_some_and(|limit| x + width > limit);
+    if in_row > 0 && (in_row >= per_row || too_wide) {
+      row_top += row_height + config.group_gap_y;
+      x = config.start_x;
+      row_height = 0.0;
+      in_row = 0;
+    }
+    origins.push((x, row_top));
+    x += width + config.group_gap_x;
+    row_height = row_height.max(height);
+    in_row += 1;
+  }
+
+  origins
+}
+
 // ---------------------------------------------------------------------------
-// Edge routing helpers
+// Section blocks
 // ---------------------------------------------------------------------------
 
-/// Right-center point of a group box (exit point for cross-section edges).
-pub fn group_right_center(group: &GroupBox) -> (f64, f64) {
-  (group.x + group.width, group.y + group.height / 2.0)
+/// Topics of a single section, positioned relative to the top-left corner
+/// of the group's topic area (inside header and padding).
+struct SectionBlock<'a> {
+  width: f64,
+  height: f64,
+  /// `(topic, dx, dy)` offsets of each node.
+  slots: Vec<(&'a Topic, f64, f64)>,
 }
 
-/// Left-center point of a group box (entry point for cross-section edges).
-pub fn group_left_center(group: &GroupBox) -> (f64, f64) {
-  (group.x, group.y + group.height / 2.0)
+/// Total extent of `n` items of size `size` separated by `gap`.
+fn span(n: usize, size: f64, gap: f64) -> f64 {
+  n as f64 * size + n.saturating_sub(1) as f64 * gap
 }
 
-/// Bottom-center of a topic node.
-pub fn topic_bottom_center(pos: &TopicPosition, config: &LayoutConfig) -> (f64, f64) {
-  (pos.x + pos.width / 2.0, pos.y + config.node_height)
+/// Tops of rows with the given heights separated by `gap`, and their total extent.
+fn stack(heights: &[f64], gap: f64) -> (Vec<f64>, f64) {
+  let mut tops = Vec::with_capacity(heights.len());
+  let mut y = 0.0;
+  for h in heights {
+    tops.push(y);
+    y += h + gap;
+  }
+  (tops, (y - gap).max(0.0))
 }
 
-/// Top-center of a topic node.
-pub fn topic_top_center(pos: &TopicPosition) -> (f64, f64) {
-  (pos.x + pos.width / 2.0, pos.y)
+/// Height of each row: the tallest of the `(row, height)` entries in it.
+fn row_heights(cells: impl IntoIterator<Item = (usize, f64)>, rows: usize) -> Vec<f64> {
+  let mut heights = vec![0.0; rows];
+  for (row, h) in cells {
+    heights[row] = f64::max(heights[row], h);
+  }
+  heights
 }
 
-/// Right-center of a topic node.
-pub fn topic_right_center(pos: &TopicPosition, config: &LayoutConfig) -> (f64, f64) {
-  (pos.x + pos.width, pos.y + config.node_height / 2.0)
+/// Single column, one topic per row in the given order.
+fn list_block<'a>(topics: &[&'a Topic], config: &LayoutConfig) -> SectionBlock<'a> {
+  let heights: Vec<f64> = topics
+    .iter()
+    .map(|t| text::topic_height(t, config))
+    .collect();
+  let (tops, height) = stack(&heights, config.topic_gap_y);
+  let slots = topics
+    .iter()
+    .zip(tops)
+    .map(|(topic, top)| (*topic, 0.0, top))
+    .collect();
+
+  SectionBlock {
+    width: config.node_width,
+    height,
+    slots,
+  }
+}
+
+/// `Main` topics stacked and centred on top, `Sub` topics in a `cols`-wide grid below.
+/// Each grid row is as tall as its tallest node; shorter nodes are centred in it.
+fn grid_block<'a>(topics: &[&'a Topic], cols: usize, config: &LayoutConfig) -> SectionBlock<'a> {
+  let cols = cols.max(1);
+  let width = span(cols, config.node_width, config.topic_gap_x);
+  let col_step = config.node_width + config.topic_gap_x;
+
+  let (mains, subs): (Vec<&Topic>, Vec<&Topic>) = topics
+    .iter()
+    .copied()
+    .partition(|t| t.topic_type == TopicType::Main);
+
+  let cells = assign_grid_cells(&subs, cols);
+  let grid_rows = cells.iter().map(|(row, _)| row + 1).max().unwrap_or(0);
+  let sub_heights: Vec<f64> = subs.iter().map(|t| text::topic_height(t, config)).collect();
+
+  let mut heights: Vec<f64> = mains
+    .iter()
+    .map(|t| text::topic_height(t, config))
+    .collect();
+  heights.extend(row_heights(
+    cells
+      .iter()
+      .zip(&sub_heights)
+      .map(|((row, _), h)| (*row, *h)),
+    grid_rows,
+  ));
+  let (tops, height) = stack(&heights, config.topic_gap_y);
+
+  let mut slots = Vec::with_capacity(topics.len());
+  for (i, topic) in mains.iter().enumerate() {
+    slots.push((*topic, (width - config.node_width) / 2.0, tops[i]));
+  }
+
+  for ((topic, (row, col)), h) in subs.iter().zip(cells.iter()).zip(sub_heights) {
+    let r = mains.len() + row;
+    slots.push((
+      *topic,
+      *col as f64 * col_step,
+      tops[r] + (heights[r] - h) / 2.0,
+    ));
+  }
+
+  SectionBlock {
+    width,
+    height,
+    slots,
+  }
 }
 
-/// Left-center of a topic node.
-pub fn topic_left_center(pos: &TopicPosition, config: &LayoutConfig) -> (f64, f64) {
-  (pos.x, pos.y + config.node_height / 2.0)
+/// Three columns — `Left` branches, `Center` spine, `Right` branches.
+///
+/// The block width is fixed so the spine lines up across stacked sections.
+/// `row` hints pick the row within a column; unhinted topics fill the gaps.
+/// Rows are shared across columns, so branches stay level with the spine.
+fn fishbone_block<'a>(topics: &[&'a Topic], config: &LayoutConfig) -> SectionBlock<'a> {
+  let col_step = config.node_width + config.branch_gap_x;
+
+  // (topic, column, row, height)
+  let mut placed: Vec<(&Topic, f64, usize, f64)> = Vec::with_capacity(topics.len());
+
+  for (placement, col) in [
+    (Placement::Left, 0.0),
+    (Placement::Center, 1.0),
+    (Placement::Right, 2.0),
+  ] {
+    let column: Vec<&Topic> = topics
+      .iter()
+      .copied()
+      .filter(|t| t.placement == placement)
+      .collect();
+    let cells = assign_grid_cells(&column, 1);
+    for (topic, (row, _)) in column.iter().zip(cells.iter()) {
+      placed.push((*topic, col, *row, text::topic_height(topic, config)));
+    }
+  }
+
+  let rows = placed.iter().map(|p| p.2 + 1).max().unwrap_or(0);
+  let heights = row_heights(placed.iter().map(|p| (p.2, p.3)), rows);
+  let (tops, height) = stack(&heights, config.topic_gap_y);
+
+  let slots = placed
+    .into_iter()
+    .map(|(topic, col, row, h)| (topic, col * col_step, tops[row] + (heights[row] - h) / 2.0))
+    .collect();
+
+  SectionBlock {
+    width: span(3, config.node_width, config.branch_gap_x),
+    height,
+    slots,
+  }
+}
+
+/// One row per dependency layer, each row centred on the widest one.
+fn layered_block<'a>(
+  topics: &[&'a Topic],
+  dependencies: &[Dependency],
+  config: &LayoutConfig,
+) -> SectionBlock<'a> {
+  let layers = layered::layers(topics, dependencies);
+  let cols = layers.iter().map(Vec::len).max().unwrap_or(0);
+  let col_step = config.node_width + config.topic_gap_x;
+
+  let node_heights: Vec<f64> = topics
+    .iter()
+    .map(|t| text::topic_height(t, config))
+    .collect();
+  let heights: Vec<f64> = layers
+    .iter()
+    .map(|layer| layer.iter().map(|&i| node_heights[i]).fold(0.0, f64::max))
+    .collect();
+  let (tops, height) = stack(&heights, config.layer_gap_y);
+
+  let mut slots = Vec::with_capacity(topics.len());
+  for (row, layer) in layers.iter().enumerate() {
+    let indent = (cols - layer.len()) as f64 * col_step / 2.0;
+    for (col, &i) in layer.iter().enumerate() {
+      slots.push((
+        topics[i],
+        indent + col as f64 * col_step,
+        tops[row] + (heights[row] - node_heights[i]) / 2.0,
+      ));
+    }
+  }
+
+  SectionBlock {
+    width: span(cols, config.node_width, config.topic_gap_x),
+    height,
+    slots,
+  }
+}
+
+/// Assign a `(row, col)` cell to each grid topic.
+///
+/// Topics with a `row` hint take the next free column of that row, spilling
+/// into the following rows when it is full. Topics without a hint then fill
+/// the remaining cells in reading order.
+fn assign_grid_cells(topics: &[&Topic], cols: usize) -> Vec<(usize, usize)> {
+  let mut filled: Vec<usize> = Vec::new();
+  let mut cells = vec![(0, 0); topics.len()];
+
+  for (i, topic) in topics.iter().enumerate() {
+    if let Some(row) = topic.row {
+      cells[i] = claim_cell(&mut filled, row, cols);
+    }
+  }
+  for (i, topic) in topics.iter().enumerate() {
+    if topic.row.is_none() {
+      cells[i] = claim_cell(&mut filled, 0, cols);
+    }
+  }
+
+  cells
+}
+
+/// Claim the first free cell at or below `row`, growing the grid as needed.
+fn claim_cell(filled: &mut Vec<usize>, mut row: usize, cols: usize) -> (usize, usize) {
+  loop {
+    if filled.len() <= row {
+      filled.resize(row + 1, 0);
+    }
+    if filled[row] < cols {
+      let col = filled[row];
+      filled[row] += 1;
+      return (row, col);
+    }
+    row += 1;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::models::roadmap::Level;
+
+  fn section(id: &'static str, order: u8, layout: SectionLayout) -> Section {
+    Section {
+      id,
+      title: id,
+      order,
+      layout,
+    }
+  }
+
+  fn topic(id: &'static str, section_id: &'static str) -> Topic {
+    Topic {
+      id,
+      title: id,
+      section_id,
+      level: Level::Beginner,
+      topic_type: TopicType::Sub,
+      placement: Placement::Center,
+      row: None,
+      estimated_hours: None,
+    }
+  }
+
+  fn dep(from: &'static str, to: &'static str) -> Dependency {
+    Dependency {
+      from,
+      to,
+      kind: DependencyKind::Prerequisite,
+      label: None,
+    }
+  }
+
+  /// One list section per id, each with a single topic `<id>_t`.
+  fn list_sections(ids: &[&'static str]) -> (Vec<Section>, Vec<Topic>) {
+    let sections = ids
+      .iter()
+      .enumerate()
+      .map(|(i, &id)| section(id, i as u8, SectionLayout::List))
+      .collect();
+    let topics = ids
+      .iter()
+      .map(|&id| topic(format!("{id}_t").leak(), id))
+      .collect();
+    (sections, topics)
+  }
+
+  fn layout(sections: &[Section], topics: &[Topic], config: &LayoutConfig) -> LayoutResult {
+    compute_layout(sections, topics, &[], &HashSet::new(), config)
+  }
+
+  fn overlaps(a: &TopicPosition, b: &TopicPosition) -> bool {
+    a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+  }
+
+  #[test]
+  fn grid_cells_do_not_collide() {
+    let sections = [section("sec", 1, SectionLayout::Grid { cols: 2 })];
+    let mut topics: Vec<Topic> = ["main", "a", "b", "c", "d"]
+      .into_iter()
+      .map(|id| topic(id, "sec"))
+      .collect();
+    topics[0].topic_type = TopicType::Main;
+    // Three topics ask for the first row of a two-column grid.
+    for t in &mut topics[1..4] {
+      t.row = Some(0);
+    }
+    topics[4].placement = Placement::Left;
+
+    for mode in LayoutMode::ALL {
+      let config = LayoutConfig {
+        mode,
+        ..LayoutConfig::default()
+      };
+      let result = layout(&sections, &topics, &config);
+      assert_eq!(result.topics.len(), topics.len(), "{mode:?}");
+      let group = &result.groups[0];
+      for (i, a) in result.topics.iter().enumerate() {
+        assert!(
+          a.x >= group.x
+            && a.y >= group.y
+            && a.x + a.width <= group.x + group.width
+            && a.y + a.height <= group.y + group.height,
+          "{mode:?}: {} outside its group",
+          a.topic_id
+        );
+        for b in &result.topics[i + 1..] {
+          assert!(
+            !overlaps(a, b),
+            "{mode:?}: {} overlaps {}",
+            a.topic_id,
+            b.topic_id
+          );
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn fishbone_branches_sit_beside_the_spine() {
+    let sections = [section("sec", 1, SectionLayout::List)];
+    let mut topics: Vec<Topic> = ["left", "spine", "right"]
+      .into_iter()
+      .map(|id| topic(id, "sec"))
+      .collect();
+    topics[0].placement = Placement::Left;
+    topics[2].placement = Placement::Right;
+    let config = LayoutConfig {
+      mode: LayoutMode::Fishbone,
+      ..LayoutConfig::default()
+    };
+    let result = layout(&sections, &topics, &config);
+    let pos = |id: &str| result.topics.iter().find(|p| p.topic_id == id).unwrap();
+
+    assert!(pos("left").x < pos("spine").x && pos("spine").x < pos("right").x);
+    assert_eq!(pos("left").y, pos("spine").y);
+    assert_eq!(pos("right").y, pos("spine").y);
+  }
+
+  #[test]
+  fn rows_wrap_at_max_width() {
+    let (sections, topics) = list_sections(&["a", "b", "c", "d", "e"]);
+    let defaults = LayoutConfig::default();
+    let group_width = defaults.node_width + 2.0 * defaults.group_padding_x;
+    // Room for two groups and their gap, but not a third.
+    let config = LayoutConfig {
+      max_width: Some(2.5 * group_width + defaults.group_gap_x),
+      ..defaults
+    };
+    let result = layout(&sections, &topics, &config);
+    let rows: Vec<f64> = result.groups.iter().map(|g| g.y).collect();
+
+    assert_eq!(rows[0], rows[1]);
+    assert!(rows[2] > rows[1]);
+    assert_eq!(rows[2], rows[3]);
+    assert!(rows[4] > rows[3]);
+    for g in &result.groups {
+      assert!(g.x + g.width <= config.start_x + config.max_width.unwrap());
+    }
+
+    let unbounded = layout(
+      &sections,
+      &topics,
+      &LayoutConfig {
+        max_width: None,
+        ..defaults
+      },
+    );
+    assert!(
+      unbounded
+        .groups
+        .iter()
+        .all(|g| g.y == unbounded.groups[0].y)
+    );
+  }
+
+  #[test]
+  fn vertical_orientation_stacks_the_groups() {
+    let (sections, topics) = list_sections(&["a", "b", "c"]);
+    let horizontal = layout(
+      &sections,
+      &topics,
+      &LayoutConfig {
+        max_width: None,
+        ..LayoutConfig::default()
+      },
+    );
+    let vertical = layout(
+      &sections,
+      &topics,
+      &LayoutConfig {
+        orientation: LayoutOrientation::Vertical,
+        max_width: None,
+        ..LayoutConfig::default()
+      },
+    );
+
+    for pair in horizontal.groups.windows(2) {
+      assert_eq!(pair[0].y, pair[1].y);
+      assert!(pair[1].x > pair[0].x + pair[0].width);
+    }
+    for pair in vertical.groups.windows(2) {
+      assert_eq!(pair[0].x, pair[1].x);
+      assert!(pair[1].y > pair[0].y + pair[0].height);
+    }
+    assert!(horizontal.total_width > vertical.total_width);
+    assert!(horizontal.total_height < vertical.total_height);
+  }
+
+  #[test]
+  fn a_collapsed_section_stands_in_for_its_topics() {
+    let sections = [
+      section("open", 1, SectionLayout::List),
+      section("shut", 2, SectionLayout::List),
+    ];
+    let topics = [topic("a", "open"), topic("b", "shut"), topic("c", "shut")];
+    let dependencies = [dep("a", "b"), dep("a", "c"), dep("b", "c")];
+    let collapsed = HashSet::from(["shut".to_string()]);
+    let config = LayoutConfig::default();
+    let result = compute_layout(&sections, &topics, &dependencies, &collapsed, &config);
+
+    let ids: Vec<&str> = result.topics.iter().map(|p| p.topic_id).collect();
+    assert_eq!(ids, ["a"]);
+    let shut = &result.groups[1];
+    assert!(shut.collapsed);
+    assert_eq!(shut.height, config.header_height);
+
+    // Both links into the section merge into one; the one inside it is dropped.
+    let edges: Vec<(&str, &str)> = result.edges.iter().map(|e| (e.from, e.to)).collect();
+    assert_eq!(edges, [("a", "shut")]);
+  }
 }
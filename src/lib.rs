@@ -3,6 +3,7 @@ pub mod components;
 pub mod data;
 pub mod layout;
 pub mod models;
+pub mod precomputed;
 pub mod routes;
 pub mod state;
 pub mod storage;
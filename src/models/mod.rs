@@ -1,3 +1,4 @@
 //! Domain models.
 
+pub mod owned;
 pub mod roadmap;
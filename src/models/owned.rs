@@ -0,0 +1,244 @@
+//! Owned, serde-friendly variants of the roadmap models.
+//!
+//! The models in `roadmap` borrow `&'static str`, which suits the compiled-in
+//! roadmap. These mirror them with `String`s so a roadmap can be read from a
+//! document at runtime (see `data::loader`), then moved into static storage
+//! with `into_static` and used like the built-in one.
+
+use crate::models::roadmap::{
+  BadgeKind, Dependency, DependencyKind, Hours, Level, Placement, Resource, Section, SectionLayout,
+  Topic, TopicContent, TopicType,
+};
+use serde::{Deserialize, Serialize};
+
+/// A whole roadmap: what `data::loader` reads from JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoadmapDocument {
+  /// Keys the reader's progress; distinct from the built-in roadmaps' IDs.
+  pub id: String,
+  /// Shown in place of the built-in roadmap's title.
+  pub title: String,
+  pub sections: Vec<OwnedSection>,
+  pub topics: Vec<OwnedTopic>,
+  /// Every edge, including the spine links between sections.
+  #[serde(default)]
+  pub dependencies: Vec<OwnedDependency>,
+  #[serde(default)]
+  pub content: Vec<OwnedTopicContent>,
+}
+
+/// Owned `Section`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OwnedSection {
+  pub id: String,
+  pub title: String,
+  pub order: u8,
+  #[serde(default = "list_layout")]
+  pub layout: SectionLayout,
+}
+
+fn list_layout() -> SectionLayout {
+  SectionLayout::List
+}
+
+/// Owned `Topic`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OwnedTopic {
+  pub id: String,
+  pub title: String,
+  pub section_id: String,
+  pub level: Level,
+  #[serde(default)]
+  pub topic_type: TopicType,
+  #[serde(default)]
+  pub placement: Placement,
+  #[serde(default)]
+  pub row: Option<usize>,
+  #[serde(default)]
+  pub estimated_hours: Option<Hours>,
+}
+
+/// Owned `Dependency`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OwnedDependency {
+  pub from: String,
+  pub to: String,
+  #[serde(default)]
+  pub kind: DependencyKind,
+  #[serde(default)]
+  pub label: Option<String>,
+}
+
+/// Owned `BadgeKind`: `"official"`, `"open_source"`, … or
+/// `{ "other": "Cheat Sheet" }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", try_from = "BadgeRepr")]
+pub enum OwnedBadge {
+  Official,
+  OpenSource,
+  Crate,
+  Article,
+  Book,
+  Video,
+  Course,
+  Interactive,
+  Podcast,
+  Newsletter,
+  Community,
+  Other(String),
+}
+
+/// How a badge is written. Parsed by hand rather than as an externally
+/// tagged enum so that YAML front matter accepts `{ other: ... }` too.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BadgeRepr {
+  Named(String),
+  Other { other: String },
+}
+
+impl TryFrom<BadgeRepr> for OwnedBadge {
+  type Error = String;
+
+  fn try_from(repr: BadgeRepr) -> Result<Self, Self::Error> {
+    let name = match repr {
+      BadgeRepr::Other { other } => return Ok(OwnedBadge::Other(other)),
+      BadgeRepr::Named(name) => name,
+    };
+    Ok(match name.as_str() {
+      "official" => OwnedBadge::Official,
+      "open_source" => OwnedBadge::OpenSource,
+      "crate" => OwnedBadge::Crate,
+      "article" => OwnedBadge::Article,
+      "book" => OwnedBadge::Book,
+      "video" => OwnedBadge::Video,
+      "course" => OwnedBadge::Course,
+      "interactive" => OwnedBadge::Interactive,
+      "podcast" => OwnedBadge::Podcast,
+      "newsletter" => OwnedBadge::Newsletter,
+      "community" => OwnedBadge::Community,
+      _ => {
+        return Err(format!(
+          "unknown badge `{name}`, expected one of `official`, `open_source`, `crate`, \
+           `article`, `book`, `video`, `course`, `interactive`, `podcast`, `newsletter`, \
+           `community`, or `{{ other: \"...\" }}`"
+        ));
+      }
+    })
+  }
+}
+
+/// Owned `Resource`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OwnedResource {
+  pub label: String,
+  pub url: String,
+  pub badge: OwnedBadge,
+}
+
+/// Owned `TopicContent`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OwnedTopicContent {
+  pub id: String,
+  pub title: String,
+  /// Paragraphs separated by a blank line.
+  #[serde(default)]
+  pub description: String,
+  #[serde(default)]
+  pub resources: Vec<OwnedResource>,
+}
+
+/// Moves `s` to static storage. Loaded roadmaps live for the rest of the
+/// session; `data::loader` moves each distinct valid document once, so the
+/// leak is bounded by what the user opens.
+fn leak(s: String) -> &'static str {
+  Box::leak(s.into_boxed_str())
+}
+
+impl OwnedSection {
+  pub fn into_static(self) -> Section {
+    Section {
+      id: leak(self.id),
+      title: leak(self.title),
+      order: self.order,
+      layout: self.layout,
+    }
+  }
+}
+
+impl OwnedTopic {
+  pub fn into_static(self) -> Topic {
+    Topic {
+      id: leak(self.id),
+      title: leak(self.title),
+      section_id: leak(self.section_id),
+      level: self.level,
+      topic_type: self.topic_type,
+      placement: self.placement,
+      row: self.row,
+      estimated_hours: self.estimated_hours,
+    }
+  }
+}
+
+impl OwnedDependency {
+  pub fn into_static(self) -> Dependency {
+    Dependency {
+      from: leak(self.from),
+      to: leak(self.to),
+      kind: self.kind,
+      label: self.label.map(leak),
+    }
+  }
+}
+
+impl OwnedBadge {
+  pub fn into_static(self) -> BadgeKind {
+    match self {
+      OwnedBadge::Official => BadgeKind::Official,
+      OwnedBadge::OpenSource => BadgeKind::OpenSource,
+      OwnedBadge::Crate => BadgeKind::Crate,
+      OwnedBadge::Article => BadgeKind::Article,
+      OwnedBadge::Book => BadgeKind::Book,
+      OwnedBadge::Video => BadgeKind::Video,
+      OwnedBadge::Course => BadgeKind::Course,
+      OwnedBadge::Interactive => BadgeKind::Interactive,
+      OwnedBadge::Podcast => BadgeKind::Podcast,
+      OwnedBadge::Newsletter => BadgeKind::Newsletter,
+      OwnedBadge::Community => BadgeKind::Community,
+      OwnedBadge::Other(label) => BadgeKind::Other(leak(label)),
+    }
+  }
+}
+
+impl OwnedResource {
+  pub fn into_static(self) -> Resource {
+    Resource {
+      label: leak(self.label),
+      url: leak(self.url),
+      badge: self.badge.into_static(),
+    }
+  }
+}
+
+impl OwnedTopicContent {
+  pub fn into_static(self) -> TopicContent {
+    TopicContent {
+      id: leak(self.id),
+      title: leak(self.title),
+      description: leak(self.description),
+      resources: self
+        .resources
+        .into_iter()
+        .map(OwnedResource::into_static)
+        .collect::<Vec<_>>()
+        .leak(),
+    }
+  }
+}
@@ -1,7 +1,13 @@
 //! Domain models for the Rust learning roadmap.
 
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+
 /// Difficulty level for a topic.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Level {
   Beginner,
   Intermediate,
@@ -40,7 +46,8 @@ impl NodeStatus {
 }
 
 /// Visual type of the topic box
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TopicType {
   Main,
   #[default]
@@ -48,7 +55,8 @@ pub enum TopicType {
 }
 
 /// Explicit layout instruction relative to the central spine.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Placement {
   /// Aligned to the central axis.
   #[default]
@@ -60,7 +68,8 @@ pub enum Placement {
 }
 
 /// Layout strategy for the section's branches.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SectionLayout {
   List,
   Grid { cols: usize },
@@ -75,6 +84,77 @@ pub struct Section {
   pub layout: SectionLayout,
 }
 
+/// Estimated study time, in whole hours: `min` to `max`, or exactly `min`
+/// when they are equal. Written as `6` or `[4, 8]` in the roadmap files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(try_from = "HoursRepr", into = "HoursRepr")]
+pub struct Hours {
+  pub min: u16,
+  pub max: u16,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum HoursRepr {
+  Exact(u16),
+  Range([u16; 2]),
+}
+
+impl TryFrom<HoursRepr> for Hours {
+  type Error = String;
+
+  fn try_from(repr: HoursRepr) -> Result<Self, Self::Error> {
+    match repr {
+      HoursRepr::Exact(hours) => Ok(Hours {
+        min: hours,
+        max: hours,
+      }),
+      HoursRepr::Range([min, max]) if min <= max => Ok(Hours { min, max }),
+      HoursRepr::Range([min, max]) => {
+        Err(format!("hours range [{min}, {max}] ends before it starts"))
+      }
+    }
+  }
+}
+
+impl From<Hours> for HoursRepr {
+  fn from(hours: Hours) -> Self {
+    if hours.min == hours.max {
+      HoursRepr::Exact(hours.min)
+    } else {
+      HoursRepr::Range([hours.min, hours.max])
+    }
+  }
+}
+
+impl Add for Hours {
+  type Output = Hours;
+
+  fn add(self, other: Hours) -> Hours {
+    Hours {
+      min: self.min.saturating_add(other.min),
+      max: self.max.saturating_add(other.max),
+    }
+  }
+}
+
+impl Sum for Hours {
+  fn sum<I: Iterator<Item = Hours>>(iter: I) -> Hours {
+    iter.fold(Hours::default(), Add::add)
+  }
+}
+
+/// `"6 h"` or `"4–8 h"`.
+impl fmt::Display for Hours {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.min == self.max {
+      write!(f, "{} h", self.min)
+    } else {
+      write!(f, "{}–{} h", self.min, self.max)
+    }
+  }
+}
+
 /// A single node in the roadmap.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Topic {
@@ -87,6 +167,56 @@ pub struct Topic {
   /// Row within the section for grid layout (0-indexed).
   /// `None` means auto-assign based on array order (backward compatible).
   pub row: Option<usize>,
+  /// `None` if nobody has estimated it yet.
+  pub estimated_hours: Option<Hours>,
+}
+
+/// Total estimate of the `topics` that have one; `None` if none do.
+pub fn total_hours<'a>(topics: impl IntoIterator<Item = &'a Topic>) -> Option<Hours> {
+  topics
+    .into_iter()
+    .filter_map(|t| t.estimated_hours)
+    .reduce(Add::add)
+}
+
+/// What a `Dependency` says about the order of its topics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyKind {
+  /// `from` must be learned before `to`.
+  #[default]
+  Prerequisite,
+  /// `from` helps with `to`, but isn't required first.
+  Recommended,
+  /// The topics are worth knowing together; neither comes first.
+  Related,
+  /// From one section's spine topic to the next section's.
+  Spine,
+}
+
+impl DependencyKind {
+  pub const ALL: [DependencyKind; 4] = [
+    DependencyKind::Prerequisite,
+    DependencyKind::Recommended,
+    DependencyKind::Related,
+    DependencyKind::Spine,
+  ];
+
+  /// Whether `from` comes before `to`. `Related` links don't order topics,
+  /// so they may point backwards without forming a cycle.
+  pub fn is_ordered(self) -> bool {
+    !matches!(self, DependencyKind::Related)
+  }
+
+  /// Lowercase name, as written in the roadmap files and CSS classes.
+  pub fn as_str(self) -> &'static str {
+    match self {
+      DependencyKind::Prerequisite => "prerequisite",
+      DependencyKind::Recommended => "recommended",
+      DependencyKind::Related => "related",
+      DependencyKind::Spine => "spine",
+    }
+  }
 }
 
 /// A directed edge between topics.
@@ -94,6 +224,9 @@ pub struct Topic {
 pub struct Dependency {
   pub from: &'static str,
   pub to: &'static str,
+  pub kind: DependencyKind,
+  /// Short text drawn on the edge.
+  pub label: Option<&'static str>,
 }
 
 /// Type of resource badge.
@@ -136,7 +269,9 @@ pub struct Resource {
 /// Detailed content for a topic (Title, Description, Resources).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TopicContent {
+  /// ID of the topic this content belongs to.
+  pub id: &'static str,
   pub title: &'static str,
   pub description: &'static str,
-  pub resources: Vec<Resource>,
+  pub resources: &'static [Resource],
 }
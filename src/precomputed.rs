@@ -0,0 +1,91 @@
+//! Default layouts generated at build time by `build.rs`.
+//!
+//! `LAYOUTS` holds the `compute_layout` result of each built-in roadmap for
+//! the default `LayoutConfig` with no section collapsed, in both
+//! orientations. Any other config is laid out at runtime.
+
+use crate::layout::tree::{
+  EdgeRoute, GroupBox, LayoutConfig, LayoutOrientation, LayoutResult, TopicPosition,
+};
+use crate::models::roadmap::DependencyKind;
+use std::collections::HashSet;
+
+/// `EdgeRoute` with its points in static storage.
+#[derive(Debug)]
+pub struct StaticEdge {
+  pub from: &'static str,
+  pub to: &'static str,
+  pub points: &'static [(f64, f64)],
+  pub is_cross_section: bool,
+  pub kind: DependencyKind,
+  pub label: Option<&'static str>,
+}
+
+/// `LayoutResult` in static storage.
+#[derive(Debug)]
+pub struct StaticLayout {
+  pub groups: &'static [GroupBox],
+  pub topics: &'static [TopicPosition],
+  pub edges: &'static [StaticEdge],
+  pub min_x: f64,
+  pub total_width: f64,
+  pub total_height: f64,
+}
+
+impl StaticLayout {
+  pub fn to_layout(&self) -> LayoutResult {
+    LayoutResult {
+      groups: self.groups.to_vec(),
+      topics: self.topics.to_vec(),
+      edges: self
+        .edges
+        .iter()
+        .map(|e| EdgeRoute {
+          from: e.from,
+          to: e.to,
+          points: e.points.to_vec(),
+          is_cross_section: e.is_cross_section,
+          kind: e.kind,
+          label: e.label,
+        })
+        .collect(),
+      min_x: self.min_x,
+      total_width: self.total_width,
+      total_height: self.total_height,
+    }
+  }
+}
+
+/// The default layouts of one built-in roadmap.
+#[derive(Debug)]
+pub struct TrackLayouts {
+  pub roadmap_id: &'static str,
+  pub horizontal: StaticLayout,
+  pub vertical: StaticLayout,
+}
+
+include!(concat!(env!("OUT_DIR"), "/layouts.rs"));
+
+/// The precomputed layout of built-in roadmap `roadmap_id` for `config`, if
+/// there is one: the default config (in either orientation) with nothing
+/// collapsed.
+pub fn layout(
+  roadmap_id: &str,
+  config: &LayoutConfig,
+  collapsed: &HashSet<String>,
+) -> Option<LayoutResult> {
+  let default = LayoutConfig {
+    orientation: config.orientation,
+    ..LayoutConfig::default()
+  };
+  if !collapsed.is_empty() || *config != default {
+    return None;
+  }
+
+  let layouts = LAYOUTS.iter().find(|l| l.roadmap_id == roadmap_id)?;
+  let layout = match config.orientation {
+    LayoutOrientation::Horizontal => &layouts.horizontal,
+    LayoutOrientation::Vertical => &layouts.vertical,
+  };
+  Some(layout.to_layout())
+}
@@ -3,34 +3,205 @@ use crate::components::roadmap::diagram::{DiagramData, RoadmapDiagram};
 use crate::components::ui::footer::Footer;
 use crate::components::ui::header::Header;
 use crate::components::ui::hero::Hero;
-use crate::data::get_topic_content;
-use crate::data::{SECTIONS, get_all_dependencies, get_all_topics};
-use crate::layout::tree::{LayoutConfig, compute_layout};
+use crate::data::{Preset, Roadmap, loader};
+use crate::layout::tree::{LayoutConfig, LayoutMode, LayoutOrientation, compute_layout};
+use crate::precomputed;
 use crate::state::roadmap_state::RoadmapState;
+use crate::storage::local_storage;
+use leptos::wasm_bindgen::{JsCast, JsValue};
 use leptos::*;
+use wasm_bindgen_futures::JsFuture;
 
+/// Viewports narrower than this get the vertical layout (matches the CSS breakpoint).
+const VERTICAL_BREAKPOINT_PX: f64 = 768.0;
+
+/// Orientation suited to the current window width.
+fn orientation_for_viewport() -> LayoutOrientation {
+  let width = web_sys::window()
+    .and_then(|w| w.inner_width().ok())
+    .and_then(|v| v.as_f64())
+    .unwrap_or(f64::MAX);
+
+  if width < VERTICAL_BREAKPOINT_PX {
+    LayoutOrientation::Vertical
+  } else {
+    LayoutOrientation::Horizontal
+  }
+}
+
+/// URL parameter naming a roadmap document to open instead of a built-in one.
+const ROADMAP_PARAM: &str = "roadmap";
+
+/// The `?roadmap=<url>` parameter of the page URL, if any.
+fn roadmap_url_param() -> Option<String> {
+  let search = web_sys::window()?.location().search().ok()?;
+  web_sys::UrlSearchParams::new_with_str(&search)
+    .ok()?
+    .get(ROADMAP_PARAM)
+}
+
+/// Message of a rejected JS promise.
+fn js_error(e: JsValue) -> String {
+  e.dyn_into::<js_sys::Error>()
+    .map(|e| String::from(e.message()))
+    .unwrap_or_else(|e| format!("{e:?}"))
+}
+
+async fn fetch_text(url: &str) -> Result<String, String> {
+  let window = web_sys::window().ok_or("no window")?;
+  let response: web_sys::Response = JsFuture::from(window.fetch_with_str(url))
+    .await
+    .map_err(js_error)?
+    .unchecked_into();
+  if !response.ok() {
+    return Err(format!("{url} returned HTTP {}", response.status()));
+  }
+  let text = JsFuture::from(response.text().map_err(js_error)?)
+    .await
+    .map_err(js_error)?;
+  text.as_string().ok_or_else(|| format!("{url} is not text"))
+}
+
+async fn read_file(file: &web_sys::File) -> Result<String, String> {
+  let text = JsFuture::from(file.text()).await.map_err(js_error)?;
+  text
+    .as_string()
+    .ok_or_else(|| format!("{} is not text", file.name()))
+}
+
+/// The built-in roadmap last picked in the switcher, or the primary one.
+fn current_builtin() -> Roadmap {
+  local_storage::load_current_roadmap()
+    .and_then(|id| Roadmap::builtin(&id))
+    .unwrap_or_else(Roadmap::primary)
+}
+
+/// Shows a built-in roadmap, or a JSON document from `?roadmap=<url>` or
+/// the header's file picker.
 #[component]
 pub fn RoadmapPage() -> impl IntoView {
-  let config = LayoutConfig::default();
+  let roadmap = create_rw_signal(current_builtin());
+  let preset = create_rw_signal(local_storage::load_preset().and_then(|id| Preset::find(&id)));
+  let load_error = create_rw_signal(None::<String>);
+  let layout_mode = create_rw_signal(
+    local_storage::load_layout_mode()
+      .and_then(|name| LayoutMode::from_name(&name))
+      .unwrap_or_default(),
+  );
+
+  let open = move |text: Result<String, String>| {
+    let loaded = text.and_then(|json| loader::load_json(&json).map_err(|e| e.to_string()));
+    match loaded {
+      Ok(loaded) => {
+        load_error.set(None);
+        roadmap.set(loaded);
+      }
+      Err(e) => load_error.set(Some(format!("Couldn't open the roadmap: {e}"))),
+    }
+  };
+
+  if let Some(url) = roadmap_url_param() {
+    spawn_local(async move { open(fetch_text(&url).await) });
+  }
 
-  let topics = get_all_topics();
-  let total_topics = topics.len();
-  let dependencies = get_all_dependencies();
+  let handle_open_file = Callback::new(move |file: web_sys::File| {
+    spawn_local(async move { open(read_file(&file).await) });
+  });
+
+  let handle_switch = Callback::new(move |next: Roadmap| {
+    local_storage::save_current_roadmap(next.id);
+    load_error.set(None);
+    roadmap.set(next);
+  });
 
-  let static_topics: &'static [_] = Box::leak(topics.into_boxed_slice());
-  let static_deps: &'static [_] = Box::leak(dependencies.into_boxed_slice());
+  let handle_preset = Callback::new(move |next: Option<&'static Preset>| {
+    local_storage::save_preset(next.map(|p| p.id));
+    preset.set(next);
+  });
 
-  let layout = compute_layout(SECTIONS, static_topics, static_deps, &config);
+  let handle_layout_mode = Callback::new(move |next: LayoutMode| {
+    local_storage::save_layout_mode(next.as_str());
+    layout_mode.set(next);
+  });
+
+  let handle_reset = Callback::new(move |_: ()| {
+    load_error.set(None);
+    roadmap.set(current_builtin());
+  });
+
+  // Everything below keeps per-roadmap state, so it is rebuilt when the
+  // roadmap or preset changes.
+  move || {
+    view! {
+        <RoadmapView
+            roadmap=roadmap.get()
+            preset=preset.get()
+            load_error=load_error.into()
+            on_switch=handle_switch
+            on_preset=handle_preset
+            layout_mode=layout_mode.into()
+            on_layout_mode=handle_layout_mode
+            on_open_file=handle_open_file
+            on_reset=handle_reset
+        />
+    }
+  }
+}
+
+#[component]
+fn RoadmapView(
+  roadmap: Roadmap,
+  load_error: Signal<Option<String>>,
+  on_switch: Callback<Roadmap>,
+  /// The role preset picked; ignored if `roadmap` doesn't offer it.
+  preset: Option<&'static Preset>,
+  on_preset: Callback<Option<&'static Preset>>,
+  layout_mode: Signal<LayoutMode>,
+  on_layout_mode: Callback<LayoutMode>,
+  on_open_file: Callback<web_sys::File>,
+  /// Return to the built-in roadmaps.
+  on_reset: Callback<()>,
+) -> impl IntoView {
+  // The preset's cut of the roadmap, if it has one; the rest of the page
+  // works on that.
+  let preset = preset.filter(|p| roadmap.with_preset(p.id).is_some());
+  let shown = preset
+    .and_then(|p| roadmap.with_preset(p.id))
+    .unwrap_or(roadmap);
+
+  // Re-layout only when the viewport crosses the breakpoint.
+  let orientation = create_rw_signal(orientation_for_viewport());
+
+  create_effect(move |_| {
+    if let Some(window) = web_sys::window() {
+      let closure = leptos::wasm_bindgen::closure::Closure::wrap(Box::new(move || {
+        let next = orientation_for_viewport();
+        if orientation.get_untracked() != next {
+          orientation.set(next);
+        }
+      }) as Box<dyn Fn()>);
+
+      let _ = window.add_event_listener_with_callback("resize", closure.as_ref().unchecked_ref());
+
+      on_cleanup(move || {
+        if let Some(window) = web_sys::window() {
+          let _ =
+            window.remove_event_listener_with_callback("resize", closure.as_ref().unchecked_ref());
+        }
+      });
+    }
+  });
 
   // -----------------------------------------------------------------------
   // Global state — provide via context so all child components can access it
   // -----------------------------------------------------------------------
-  let state = RoadmapState::new(total_topics);
+  let state = RoadmapState::new(roadmap.id, shown.topics);
   provide_context(state);
 
   // Convenience aliases
   let search_term = state.search_term;
   let selected_topic_id = state.selected_topic_id;
+  let collapsed_sections = state.collapsed_sections;
 
   // -----------------------------------------------------------------------
   // Callbacks
@@ -51,11 +222,35 @@ pub fn RoadmapPage() -> impl IntoView {
   // -----------------------------------------------------------------------
   // Diagram props (on_topic_click now lives inside RoadmapDiagram via context)
   // -----------------------------------------------------------------------
-  let diagram_props = DiagramData {
-    topics: static_topics,
-    dependencies: static_deps,
-    layout,
-    config,
+  let diagram_props = move || {
+    let config = LayoutConfig {
+      mode: layout_mode.get(),
+      orientation: orientation.get(),
+      ..LayoutConfig::default()
+    };
+    // The built-in roadmaps' default layouts are embedded at build time;
+    // anything else, including a preset's cut, is laid out here.
+    let layout = collapsed_sections.with(|collapsed| {
+      shown
+        .is_builtin()
+        .then(|| precomputed::layout(shown.id, &config, collapsed))
+        .flatten()
+        .unwrap_or_else(|| {
+          compute_layout(
+            shown.sections,
+            shown.topics,
+            shown.dependencies,
+            collapsed,
+            &config,
+          )
+        })
+    });
+
+    DiagramData {
+      topics: shown.topics,
+      layout,
+      config,
+    }
   };
 
   view! {
@@ -67,15 +262,40 @@ pub fn RoadmapPage() -> impl IntoView {
           </div>
 
           // Sticky header (contains search + progress bar)
-          <Header search_term=search_term.read_only() on_search=handle_search />
+          <Header
+              roadmap=roadmap
+              on_switch=on_switch
+              preset=preset
+              on_preset=on_preset
+              layout_mode=layout_mode
+              on_layout_mode=on_layout_mode
+              search_term=search_term.read_only()
+              on_search=handle_search
+              on_open_file=on_open_file
+          />
 
           // Main content
           <main class="main-content">
+              {move || {
+                  load_error
+                      .get()
+                      .map(|e| view! { <div class="roadmap-banner roadmap-banner--error" role="alert">{e}</div> })
+              }}
+              {(!roadmap.is_builtin()).then(|| view! {
+                  <div class="roadmap-banner">
+                      <span>"Showing " <strong>{roadmap.title}</strong></span>
+                      <button class="roadmap-banner__action" on:click=move |_| on_reset.call(())>
+                          "Back to the built-in roadmaps"
+                      </button>
+                  </div>
+              })}
+
               <Hero />
 
-              // Horizontally-scrollable roadmap canvas
+              // Scrollable roadmap canvas (re-rendered when the orientation flips,
+              // the layout mode changes or a section is collapsed / expanded)
               <div class="roadmap-container">
-                  <RoadmapDiagram props=diagram_props />
+                  {move || view! { <RoadmapDiagram props=diagram_props() /> }}
               </div>
           </main>
 
@@ -96,15 +316,13 @@ pub fn RoadmapPage() -> impl IntoView {
           // Detail drawer — re-mounts whenever the selected topic changes
           {move || {
               let topic_id = selected_topic_id.get()?;
-              let content = get_topic_content(topic_id)?;
-
-              // Look up the section label from static data
-              let section_label = static_topics
+              let content = shown.topic_content(topic_id)?;
+              let section_label = shown.section_of(topic_id).map(|s| s.title).unwrap_or("");
+              let estimated_hours = shown
+                  .topics
                   .iter()
                   .find(|t| t.id == topic_id)
-                  .and_then(|t| SECTIONS.iter().find(|s| s.id == t.section_id))
-                  .map(|s| s.title)
-                  .unwrap_or("");
+                  .and_then(|t| t.estimated_hours);
 
               Some(view! {
                   <TopicDetail
@@ -113,6 +331,7 @@ pub fn RoadmapPage() -> impl IntoView {
                       is_open=is_drawer_open.get()
                       topic_id=topic_id
                       section_label=section_label
+                      estimated_hours=estimated_hours
                   />
               })
           }}
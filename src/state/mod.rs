@@ -1 +1,2 @@
 pub mod roadmap_state;
+pub mod view_box;
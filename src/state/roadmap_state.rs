@@ -1,10 +1,11 @@
 //! Global reactive state for the roadmap.
 //! Provide once at the page root; access anywhere via `use_context::<RoadmapState>()`.
 
-use crate::models::roadmap::NodeStatus;
+use crate::models::roadmap::{Hours, NodeStatus, Topic, total_hours};
+use crate::state::view_box::ViewBox;
 use crate::storage::local_storage;
 use leptos::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Central state store passed through Leptos context.
 /// All fields are `Copy` signal handles, so the struct itself is `Copy`.
@@ -19,30 +20,47 @@ pub struct RoadmapState {
   /// Per-topic progress. Keys are owned Strings (topic IDs).
   pub progress: RwSignal<HashMap<String, NodeStatus>>,
 
-  /// Total number of topics (static, set once at startup).
+  /// IDs of the sections collapsed to their header bar.
+  pub collapsed_sections: RwSignal<HashSet<String>>,
+
+  /// Visible region of the diagram (pan / zoom); set once the canvas is measured.
+  pub view_box: RwSignal<ViewBox>,
+
+  /// Number of topics on the roadmap shown (after any preset).
   pub total_topics: usize,
 
-  /// Reactive count of Done + Skipped topics.
+  /// Reactive count of Done + Skipped topics among those shown.
   pub completed_count: Memo<usize>,
 
   /// Reactive completion percentage 0.0 – 100.0.
   pub completion_pct: Memo<f64>,
+
+  /// Estimate for all the topics shown; `None` if none has one.
+  pub total_hours: Option<Hours>,
+
+  /// Reactive estimate for the topics shown that aren't Done or Skipped.
+  pub hours_remaining: Memo<Hours>,
 }
 
 impl RoadmapState {
-  /// Create a new `RoadmapState`.
+  /// Create a new `RoadmapState` for roadmap `roadmap_id`, whose progress is
+  /// stored apart from every other roadmap's. Completion counts `topics`
+  /// only, so a preset's hidden topics don't count towards it.
   /// **Must** be called inside a Leptos reactive root (i.e. within a component).
-  pub fn new(total_topics: usize) -> Self {
+  pub fn new(roadmap_id: &'static str, topics: &'static [Topic]) -> Self {
+    let total_topics = topics.len();
+
     // Hydrate initial progress from localStorage.
-    let initial = local_storage::load_progress();
+    let initial = local_storage::load_progress(roadmap_id);
     let progress = create_rw_signal(initial);
 
     let completed_count = create_memo(move |_| {
-      progress
-        .get()
-        .values()
-        .filter(|s| matches!(s, NodeStatus::Done | NodeStatus::Skipped))
-        .count()
+      progress.with(|map| {
+        topics
+          .iter()
+          .filter(|t| matches!(map.get(t.id), Some(NodeStatus::Done | NodeStatus::Skipped)))
+          .count()
+      })
     });
 
     let completion_pct = create_memo(move |_| {
@@ -52,19 +70,38 @@ impl RoadmapState {
       (completed_count.get() as f64 / total_topics as f64) * 100.0
     });
 
+    let hours_remaining = create_memo(move |_| {
+      progress.with(|map| {
+        topics
+          .iter()
+          .filter(|t| !matches!(map.get(t.id), Some(NodeStatus::Done | NodeStatus::Skipped)))
+          .filter_map(|t| t.estimated_hours)
+          .sum()
+      })
+    });
+
     // Auto-persist to localStorage whenever progress changes.
     create_effect(move |_| {
       let p = progress.get();
-      local_storage::save_progress(&p);
+      local_storage::save_progress(roadmap_id, &p);
+    });
+
+    let collapsed_sections = create_rw_signal(local_storage::load_collapsed(roadmap_id));
+    create_effect(move |_| {
+      collapsed_sections.with(|c| local_storage::save_collapsed(roadmap_id, c));
     });
 
     Self {
       search_term: create_rw_signal(String::new()),
       selected_topic_id: create_rw_signal(None),
       progress,
+      collapsed_sections,
+      view_box: create_rw_signal(ViewBox::default()),
       total_topics,
       completed_count,
       completion_pct,
+      total_hours: total_hours(topics),
+      hours_remaining,
     }
   }
 
@@ -78,6 +115,16 @@ impl RoadmapState {
       .unwrap_or_default()
   }
 
+  /// Number of Done + Skipped topics among `topic_ids` (reactive – reads the signal).
+  pub fn completed_among(&self, topic_ids: &[&str]) -> usize {
+    self.progress.with(|map| {
+      topic_ids
+        .iter()
+        .filter(|id| matches!(map.get(**id), Some(NodeStatus::Done | NodeStatus::Skipped)))
+        .count()
+    })
+  }
+
   /// Set a specific status for a topic.
   /// Removes the entry if `status` is `Untouched` to keep the map lean.
   pub fn set_status(&self, topic_id: &'static str, status: NodeStatus) {
@@ -102,4 +149,13 @@ impl RoadmapState {
       }
     });
   }
+
+  /// Collapse the section if expanded, expand it if collapsed.
+  pub fn toggle_section(&self, section_id: &'static str) {
+    self.collapsed_sections.update(|set| {
+      if !set.remove(section_id) {
+        set.insert(section_id.to_string());
+      }
+    });
+  }
 }
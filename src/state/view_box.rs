@@ -0,0 +1,141 @@
+//! Pan / zoom transform of the roadmap canvas, expressed as an SVG `viewBox`.
+//!
+//! The SVG renders with `preserveAspectRatio="xMinYMin meet"`, so one client
+//! pixel covers `units_per_px` diagram units and the top-left corners line up.
+
+/// Closest the canvas zooms out (client pixels per diagram unit).
+const MIN_ZOOM: f64 = 0.1;
+/// Closest the canvas zooms in (client pixels per diagram unit).
+const MAX_ZOOM: f64 = 4.0;
+/// Below this zoom, topic titles are too small to read and sections are
+/// drawn as summary tiles instead.
+const OVERVIEW_ZOOM: f64 = 0.45;
+
+/// How much of the diagram is drawn at the current zoom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DetailLevel {
+  /// One summary tile per section; no topic nodes or intra-section edges.
+  Overview,
+  /// Every topic node and edge.
+  #[default]
+  Topics,
+}
+
+impl DetailLevel {
+  /// Level to draw at `zoom` client pixels per diagram unit.
+  pub fn at_zoom(zoom: f64) -> Self {
+    if zoom < OVERVIEW_ZOOM {
+      DetailLevel::Overview
+    } else {
+      DetailLevel::Topics
+    }
+  }
+}
+
+/// Visible region of the diagram, in diagram (layout) units.
+/// A zero-sized box means "not measured yet".
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ViewBox {
+  pub x: f64,
+  pub y: f64,
+  pub width: f64,
+  pub height: f64,
+}
+
+impl ViewBox {
+  /// Whether the view has been set up against a measured canvas.
+  pub fn is_set(&self) -> bool {
+    self.width > 0.0 && self.height > 0.0
+  }
+
+  /// 1:1 view with `(x, y)` in the top-left corner of a `client`-sized canvas.
+  pub fn actual_size(x: f64, y: f64, client: (f64, f64)) -> Self {
+    Self {
+      x,
+      y,
+      width: client.0,
+      height: client.1,
+    }
+  }
+
+  /// Smallest view of the `client` aspect ratio that shows all of `content`
+  /// (`x, y, width, height`), centred.
+  pub fn fit(content: (f64, f64, f64, f64), client: (f64, f64)) -> Self {
+    let (x, y, w, h) = content;
+    let s = (w / client.0).max(h / client.1);
+    let width = client.0 * s;
+    let height = client.1 * s;
+    Self {
+      x: x - (width - w) / 2.0,
+      y: y - (height - h) / 2.0,
+      width,
+      height,
+    }
+  }
+
+  /// Diagram units covered by one client pixel.
+  pub fn units_per_px(&self, client: (f64, f64)) -> f64 {
+    (self.width / client.0).max(self.height / client.1)
+  }
+
+  /// Level of detail for this view on a `client`-sized canvas.
+  pub fn detail_level(&self, client: (f64, f64)) -> DetailLevel {
+    if self.is_set() {
+      DetailLevel::at_zoom(1.0 / self.units_per_px(client))
+    } else {
+      DetailLevel::Topics
+    }
+  }
+
+  /// Region actually shown on a `client`-sized canvas, `(x, y, width, height)`.
+  /// Wider or taller than the box itself when the aspect ratios differ.
+  pub fn visible(&self, client: (f64, f64)) -> (f64, f64, f64, f64) {
+    let s = self.units_per_px(client);
+    (self.x, self.y, client.0 * s, client.1 * s)
+  }
+
+  /// Diagram point under the client point `p` (relative to the canvas corner).
+  pub fn to_diagram(&self, p: (f64, f64), client: (f64, f64)) -> (f64, f64) {
+    let s = self.units_per_px(client);
+    (self.x + p.0 * s, self.y + p.1 * s)
+  }
+
+  /// Move the view by `(dx, dy)` client pixels (content follows the pointer).
+  pub fn panned(self, dx: f64, dy: f64, client: (f64, f64)) -> Self {
+    let s = self.units_per_px(client);
+    Self {
+      x: self.x - dx * s,
+      y: self.y - dy * s,
+      ..self
+    }
+  }
+
+  /// Zoom in by `factor` (> 1 zooms in) keeping the diagram point `anchor`
+  /// fixed on screen. The zoom level stays within `MIN_ZOOM..=MAX_ZOOM`.
+  pub fn zoomed_at(self, factor: f64, anchor: (f64, f64), client: (f64, f64)) -> Self {
+    let zoom = 1.0 / self.units_per_px(client);
+    let factor = (zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM) / zoom;
+    Self {
+      x: anchor.0 - (anchor.0 - self.x) / factor,
+      y: anchor.1 - (anchor.1 - self.y) / factor,
+      width: self.width / factor,
+      height: self.height / factor,
+    }
+  }
+
+  /// Same zoom, moved so `(x, y)` sits in the middle of a `client`-sized
+  /// canvas.
+  pub fn centered_on(self, x: f64, y: f64, client: (f64, f64)) -> Self {
+    let (_, _, width, height) = self.visible(client);
+    Self {
+      x: x - width / 2.0,
+      y: y - height / 2.0,
+      ..self
+    }
+  }
+
+  /// Value for the SVG `viewBox` attribute.
+  pub fn to_attr(&self) -> String {
+    format!("{} {} {} {}", self.x, self.y, self.width, self.height)
+  }
+}
@@ -1,13 +1,43 @@
-//! localStorage wrapper for progress persistence.
+//! localStorage wrapper for progress and view-state persistence.
 
 use crate::models::roadmap::NodeStatus;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+/// Progress and collapsed sections are stored per roadmap, under
+/// `"<key>:<roadmap id>"`.
 const STORAGE_KEY: &str = "rust_roadmap_progress";
+const COLLAPSED_KEY: &str = "rust_roadmap_collapsed";
+/// The built-in roadmap last picked in the switcher.
+const CURRENT_ROADMAP_KEY: &str = "rust_roadmap_current";
+/// The role preset picked in the header, for every roadmap.
+const PRESET_KEY: &str = "rust_roadmap_preset";
+/// The layout mode picked in the header, for every roadmap.
+const LAYOUT_MODE_KEY: &str = "rust_roadmap_layout";
 
-/// Persist the progress map to `localStorage`.
+/// The roadmap whose state was stored under the bare keys, before there was
+/// more than one. It picks that state up until it saves its own.
+const LEGACY_ROADMAP_ID: &str = "rust";
+
+fn roadmap_key(key: &str, roadmap_id: &str) -> String {
+  format!("{key}:{roadmap_id}")
+}
+
+/// The value stored under `key` for `roadmap_id`, falling back to the bare
+/// key for the legacy roadmap.
+fn load_for_roadmap(storage: &web_sys::Storage, key: &str, roadmap_id: &str) -> Option<String> {
+  let stored = storage
+    .get_item(&roadmap_key(key, roadmap_id))
+    .ok()
+    .flatten();
+  match stored {
+    None if roadmap_id == LEGACY_ROADMAP_ID => storage.get_item(key).ok().flatten(),
+    stored => stored,
+  }
+}
+
+/// Persist roadmap `roadmap_id`'s progress map to `localStorage`.
 /// Format: pipe-separated `"id:status"` pairs, e.g. `"intro:d|setup_env:p"`.
-pub fn save_progress(progress: &HashMap<String, NodeStatus>) {
+pub fn save_progress(roadmap_id: &str, progress: &HashMap<String, NodeStatus>) {
   let window = match web_sys::window() {
     Some(w) => w,
     None => return,
@@ -24,12 +54,12 @@ pub fn save_progress(progress: &HashMap<String, NodeStatus>) {
     .collect::<Vec<_>>()
     .join("|");
 
-  let _ = storage.set_item(STORAGE_KEY, &serialized);
+  let _ = storage.set_item(&roadmap_key(STORAGE_KEY, roadmap_id), &serialized);
 }
 
-/// Load the progress map from `localStorage`.
+/// Load roadmap `roadmap_id`'s progress map from `localStorage`.
 /// Returns an empty map if nothing is stored or parsing fails.
-pub fn load_progress() -> HashMap<String, NodeStatus> {
+pub fn load_progress(roadmap_id: &str) -> HashMap<String, NodeStatus> {
   let mut map = HashMap::new();
 
   let window = match web_sys::window() {
@@ -42,8 +72,8 @@ pub fn load_progress() -> HashMap<String, NodeStatus> {
     _ => return map,
   };
 
-  let raw = match storage.get_item(STORAGE_KEY) {
-    Ok(Some(s)) if !s.is_empty() => s,
+  let raw = match load_for_roadmap(&storage, STORAGE_KEY, roadmap_id) {
+    Some(s) if !s.is_empty() => s,
     _ => return map,
   };
 
@@ -59,6 +89,78 @@ pub fn load_progress() -> HashMap<String, NodeStatus> {
   map
 }
 
+/// Persist roadmap `roadmap_id`'s set of collapsed section IDs, pipe-separated.
+pub fn save_collapsed(roadmap_id: &str, collapsed: &HashSet<String>) {
+  let Some(storage) = local_storage() else {
+    return;
+  };
+
+  let mut ids: Vec<&str> = collapsed.iter().map(String::as_str).collect();
+  ids.sort_unstable();
+  let _ = storage.set_item(&roadmap_key(COLLAPSED_KEY, roadmap_id), &ids.join("|"));
+}
+
+/// Load roadmap `roadmap_id`'s set of collapsed section IDs; empty if
+/// nothing is stored.
+pub fn load_collapsed(roadmap_id: &str) -> HashSet<String> {
+  let raw = local_storage()
+    .and_then(|s| load_for_roadmap(&s, COLLAPSED_KEY, roadmap_id))
+    .unwrap_or_default();
+
+  raw
+    .split('|')
+    .filter(|id| !id.is_empty())
+    .map(str::to_string)
+    .collect()
+}
+
+/// Remember the built-in roadmap picked in the switcher.
+pub fn save_current_roadmap(roadmap_id: &str) {
+  if let Some(storage) = local_storage() {
+    let _ = storage.set_item(CURRENT_ROADMAP_KEY, roadmap_id);
+  }
+}
+
+/// The built-in roadmap last picked in the switcher, if any.
+pub fn load_current_roadmap() -> Option<String> {
+  local_storage()?
+    .get_item(CURRENT_ROADMAP_KEY)
+    .ok()
+    .flatten()
+}
+
+/// Remember the role preset picked in the header; `None` shows every section.
+pub fn save_preset(preset_id: Option<&str>) {
+  let Some(storage) = local_storage() else {
+    return;
+  };
+  let _ = match preset_id {
+    Some(id) => storage.set_item(PRESET_KEY, id),
+    None => storage.remove_item(PRESET_KEY),
+  };
+}
+
+/// The role preset last picked, if any.
+pub fn load_preset() -> Option<String> {
+  local_storage()?.get_item(PRESET_KEY).ok().flatten()
+}
+
+/// Remember the layout mode picked in the header.
+pub fn save_layout_mode(mode: &str) {
+  if let Some(storage) = local_storage() {
+    let _ = storage.set_item(LAYOUT_MODE_KEY, mode);
+  }
+}
+
+/// The layout mode last picked, if any.
+pub fn load_layout_mode() -> Option<String> {
+  local_storage()?.get_item(LAYOUT_MODE_KEY).ok().flatten()
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+  web_sys::window()?.local_storage().ok().flatten()
+}
+
 fn status_to_str(status: NodeStatus) -> &'static str {
   match status {
     NodeStatus::Untouched => "u",